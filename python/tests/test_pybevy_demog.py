@@ -17,6 +17,7 @@ results = bevy_abm.run_simulation({
 print(f"  Births: {len(results['births'])} events")
 print(f"  Deaths: {len(results['deaths'])} events")
 print(f"  Partnerships: {len(results['partnerships'])} events")
+print(f"  Marriages: {len(results['marriages'])} events")
 print(f"  Breakups: {len(results['breakups'])} events")
 print(f"  Widowings: {len(results['widowings'])} events")
 assert isinstance(results['births'], pl.DataFrame)
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::individual::{Demog, Adult, Elder};
+use crate::partner::Partner;
+use crate::household::{Household, HouseholdMember};
+use crate::orphanhood::{Orphanhood, OrphanhoodKind};
+use crate::patch::{InPatch, Patches};
+use crate::vaccination::Vaccinated;
+use crate::config::SimulationParameters;
+
+#[derive(Serialize, Clone)]
+pub struct HouseholdCensusRecord {
+    pub time: f32,
+    pub patch: Option<usize>,
+    pub household: Entity,
+    pub size: usize,
+    pub children: usize,
+    pub adults: usize,  // excluding elders
+    pub elders: usize,
+    pub couples: usize,
+}
+
+#[derive(Serialize, Clone)]
+pub struct HouseholdSizeRecord {
+    pub time: f32,
+    pub patch: Option<usize>,
+    pub size: usize,
+    pub count: usize,
+}
+
+// Orphans by single year of age, below orphan_age_limit
+#[derive(Serialize, Clone)]
+pub struct OrphanCensusRecord {
+    pub time: f32,
+    pub patch: Option<usize>,
+    pub age: u32,
+    pub maternal: usize,  // mother dead, father alive or unknown
+    pub paternal: usize,  // father dead, mother alive
+    pub double: usize,
+}
+
+// Vaccinated individuals by single year of age, only taken when vaccination is configured
+#[derive(Serialize, Clone)]
+pub struct VaccinationCensusRecord {
+    pub time: f32,
+    pub patch: Option<usize>,
+    pub age: u32,
+    pub population: usize,
+    pub vaccinated: usize,
+}
+
+// Periodic snapshots of the population, one table per kind of record; patch is None without patches
+#[derive(Resource, Default, Clone)]
+pub struct Census {
+    pub households: Vec<HouseholdCensusRecord>,
+    pub household_sizes: Vec<HouseholdSizeRecord>,
+    pub orphans: Vec<OrphanCensusRecord>,
+    pub vaccinations: Vec<VaccinationCensusRecord>,
+}
+
+pub struct CensusPlugin;
+
+impl Plugin for CensusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Census>()
+            .add_systems(Update, take_census.run_if(on_timer(Duration::from_secs_f32(CENSUS_TIMESTEP))))
+            .add_systems(bevy::app::Last, print_census_summary);
+    }
+}
+
+//-- CENSUS
+const CENSUS_TIMESTEP: f32 = 1.0;
+
+// A household member and the family roles and patch the census tallies
+type Resident = (Entity, &'static HouseholdMember, Option<&'static Adult>, Option<&'static Elder>, Option<&'static Partner>, Option<&'static InPatch>);
+
+// An individual's age, orphanhood and vaccination status, by patch
+type Person = (&'static Demog, Option<&'static Orphanhood>, Option<&'static Vaccinated>, Option<&'static InPatch>);
+
+pub fn take_census(
+    mut census: ResMut<Census>,
+    household_query: Query<Entity, With<Household>>,
+    member_query: Query<Resident, With<Demog>>,
+    person_query: Query<Person>,
+    params: Res<SimulationParameters>,
+    patches: Option<Res<Patches>>,
+    time: Res<Time>
+) {
+    let now = time.elapsed_seconds();
+
+    let mut records: HashMap<Entity, HouseholdCensusRecord> = household_query.iter()
+        .map(|household| (household, HouseholdCensusRecord {
+            time: now,
+            patch: None,
+            household,
+            size: 0,
+            children: 0,
+            adults: 0,
+            elders: 0,
+            couples: 0,
+        }))
+        .collect();
+    let households: HashMap<Entity, Entity> = member_query.iter()
+        .map(|(e, member, _, _, _, _)| (e, member.0))
+        .collect();
+
+    for (e, member, adult_opt, elder_opt, partner_opt, patch_opt) in member_query.iter() {
+        if let Some(record) = records.get_mut(&member.0) {
+            record.size += 1;
+            record.patch = record.patch.or(patch_opt.map(|p| p.0));
+            if elder_opt.is_some() {
+                record.elders += 1;
+            } else if adult_opt.is_some() {
+                record.adults += 1;
+            } else {
+                record.children += 1;
+            }
+
+            // Count each co-resident couple once
+            if let Some(partner) = partner_opt {
+                if e < partner.0 && households.get(&partner.0) == Some(&member.0) {
+                    record.couples += 1;
+                }
+            }
+        }
+    }
+
+    let mut size_counts: BTreeMap<(Option<usize>, usize), usize> = BTreeMap::new();
+    for record in records.values().filter(|r| r.size > 0) {
+        *size_counts.entry((record.patch, record.size)).or_default() += 1;
+    }
+
+    debug!("Census at time {:.2}: {} households", now, records.len());
+    census.household_sizes.extend(size_counts.into_iter().map(|((patch, size), count)| HouseholdSizeRecord {
+        time: now,
+        patch,
+        size,
+        count,
+    }));
+    census.households.extend(records.into_values().filter(|r| r.size > 0));
+
+    // One row per age in every patch, including ages without orphans
+    let patch_ids: Vec<Option<usize>> = match patches.as_deref() {
+        Some(patches) if !patches.patches.is_empty() => (0..patches.patches.len()).map(Some).collect(),
+        _ => vec![None],
+    };
+    let mut orphan_counts: BTreeMap<(Option<usize>, u32), OrphanCensusRecord> = patch_ids.iter()
+        .flat_map(|patch| (0..params.orphan_age_limit.ceil() as u32)
+            .map(|age| ((*patch, age), OrphanCensusRecord { time: now, patch: *patch, age, maternal: 0, paternal: 0, double: 0 })))
+        .collect();
+    for (demog, orphanhood_opt, _, patch_opt) in person_query.iter() {
+        let Some(orphanhood) = orphanhood_opt else {
+            continue;
+        };
+        if demog.age >= params.orphan_age_limit {
+            continue;
+        }
+        if let Some(record) = orphan_counts.get_mut(&(patch_opt.map(|p| p.0), demog.age as u32)) {
+            match orphanhood.kind() {
+                Some(OrphanhoodKind::Maternal) => record.maternal += 1,
+                Some(OrphanhoodKind::Paternal) => record.paternal += 1,
+                Some(OrphanhoodKind::Double) => record.double += 1,
+                None => {}
+            }
+        }
+    }
+    census.orphans.extend(orphan_counts.into_values());
+
+    if params.routine_vaccinations.is_empty() && params.vaccination_campaigns.is_empty() {
+        return;
+    }
+    let mut vaccination_counts: BTreeMap<(Option<usize>, u32), VaccinationCensusRecord> = BTreeMap::new();
+    for (demog, _, vaccinated_opt, patch_opt) in person_query.iter() {
+        let patch = patch_opt.map(|p| p.0);
+        let age = demog.age as u32;
+        let record = vaccination_counts.entry((patch, age))
+            .or_insert(VaccinationCensusRecord { time: now, patch, age, population: 0, vaccinated: 0 });
+        record.population += 1;
+        if vaccinated_opt.is_some() {
+            record.vaccinated += 1;
+        }
+    }
+    census.vaccinations.extend(vaccination_counts.into_values());
+}
+
+fn print_census_summary(
+    census: Res<Census>,
+    exit_events: EventReader<bevy::app::AppExit>
+) {
+    if !exit_events.is_empty() {
+        if let Some(last) = census.households.last() {
+            let latest: Vec<&HouseholdCensusRecord> = census.households.iter()
+                .filter(|r| r.time == last.time)
+                .collect();
+            let mean_size = latest.iter().map(|r| r.size).sum::<usize>() as f32 / latest.len() as f32;
+
+            info!("\n========== CENSUS SUMMARY ==========");
+            info!("Last census at time {:.2}", last.time);
+            info!("Households:          {}", latest.len());
+            info!("Mean household size: {:.2}", mean_size);
+            let orphans: usize = census.orphans.iter()
+                .filter(|r| r.time == last.time)
+                .map(|r| r.maternal + r.paternal + r.double)
+                .sum();
+            info!("Orphans:             {}", orphans);
+            let vaccinated: usize = census.vaccinations.iter()
+                .filter(|r| r.time == last.time)
+                .map(|r| r.vaccinated)
+                .sum();
+            if vaccinated > 0 {
+                info!("Vaccinated:          {}", vaccinated);
+            }
+            info!("====================================\n");
+        }
+    }
+}
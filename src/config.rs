@@ -2,6 +2,10 @@ use bevy::prelude::*;
 use bevy::app::AppExit;
 use clap::Parser;
 
+use crate::emigration::EmigrationRate;
+use crate::gestation::{PregnancyLossHazard, MultipleBirthRate};
+use crate::vaccination::{RoutineVaccination, VaccinationCampaign};
+
 #[derive(Parser, Debug, Resource)]
 #[command(version, about, long_about = None)]
 pub struct Args {
@@ -16,6 +20,27 @@ pub struct Args {
     /// Export event log to JSON files at simulation end
     #[arg(long, default_value_t = false)]
     pub export_events: bool,
+
+    /// Export the pedigree and partnership network (GraphML, GEXF, CSV edge lists) at simulation end
+    #[arg(long, default_value_t = false)]
+    pub export_network: bool,
+
+    /// JSON file describing patches, their parameter overrides and migration between them
+    #[arg(long)]
+    pub patches: Option<String>,
+
+    /// JSON file listing timed parameter changes (set or linear ramp) to apply during the run
+    #[arg(long)]
+    pub scenario: Option<String>,
+}
+
+// Which parent children live with after a breakup, when both parents were in the union
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum CustodyRule {
+    #[default]
+    Mother,
+    Father,
+    Random,
 }
 
 #[derive(Resource, Clone)]
@@ -25,15 +50,95 @@ pub struct SimulationParameters {
     pub min_partner_seeking_age: f32,
     pub max_partner_seeking_age: f32,
     pub spawn_individual_age: f32,
+    pub homosexual_fraction: f32,
+    pub bisexual_fraction: f32,
+    pub background_mortality_rate: f32,  // annual hazard before death_age
+    pub initial_sex_ratio: f32,  // males per 100 females in the initial population
+    pub sex_ratio_at_birth: f32,  // males per 100 females
+    pub sex_ratio_by_birth_order: Vec<f32>,  // overrides sex_ratio_at_birth for 1st, 2nd, ... births, the last for all higher orders
+
+    // Density dependence - capacity in individuals over the whole grid
+    pub density_dependence: bool,
+    pub carrying_capacity: f32,
+    pub density_cell_size: f32,  // 0 for a single global population
+    pub density_fertility_strength: f32,
+    pub density_mortality_strength: f32,
+
+    // Space - grid bounds in location units
+    pub grid_width: f32,
+    pub grid_height: f32,
+    pub spatial_matching: bool,
+    pub partner_search_radius: f32,
+
+    // Households - ages and rates
+    pub leave_home_age: f32,
+    pub elder_coresidence_rate: f32,
+    pub custody_rule: CustodyRule,
+    pub orphan_age_limit: f32,
+
+    // Emigration - annual rates by age band and sex
+    pub emigration_rates: Vec<EmigrationRate>,
 
     // Reproduction - rates and durations, not timesteps
     pub min_conception_age: f32,
     pub max_conception_age: f32,
     pub conception_rate: f32,
-    pub gestation_duration: f32,
+    pub cohabitation_fertility_ratio: f32,
+    pub gestation_duration: f32,  // mean when gestation_duration_sd > 0
+    pub gestation_duration_sd: f32,  // 0 for a fixed length
+    pub pregnancy_loss_hazards: Vec<PregnancyLossHazard>,  // annual hazards by gestational age
+    pub postpartum_infecundity: f32,  // years after any delivery before conception is possible again
+    pub postpartum_infecundity_sd: f32,  // 0 for a fixed period
+    pub breastfeeding_duration: f32,  // after live births
+    pub breastfeeding_infecundity_ratio: f32,  // share of breastfeeding added to the infecund period
+    pub multiple_birth_rates: Vec<MultipleBirthRate>,  // twin and triplet probabilities by maternal age
+    pub parity_fertility_ratios: Vec<f32>,  // conception rate multipliers for parity 0, 1, ..., the last for all higher parities
+    pub desired_family_size: f32,  // mean target number of children, 0 for no stopping rule
+    pub desired_family_size_sd: f32,
+
+    // Childbirth - probabilities per delivery, infant_mortality_rate per year
+    pub maternal_mortality_prob: f32,  // for mothers aged 20-34 with 1-3 previous births
+    pub maternal_mortality_age_ratio: f32,  // relative risk for mothers under 20 or 35 and over
+    pub maternal_mortality_parity_ratio: f32,  // relative risk for first births and after 4+ previous births
+    pub stillbirth_prob: f32,
+    pub neonatal_mortality_prob: f32,  // death in the first month of life
+    pub infant_mortality_rate: f32,  // excess hazard for the rest of the first year
 
     // Relationships - rates, not timesteps
-    pub breakup_rate: f32,
+    pub breakup_rate: f32,  // separation rate of cohabiting unions
+    pub marriage_rate: f32,
+    pub divorce_rate: f32,
+
+    // Re-entry to the partner market after dissolution - durations in years
+    pub breakup_reentry_delay: f32,
+    pub widowhood_reentry_delay: f32,
+    pub reentry_delay_age_factor: f32,
+    pub no_repartnering_prob: f32,
+
+    // Concurrent partnerships (sexual contact network mode)
+    pub concurrent_partnerships: bool,
+    pub max_concurrent_partners: usize,
+    pub casual_formation_rate: f32,
+    pub casual_dissolution_rate: f32,
+    pub steady_contact_rate: f32,
+    pub casual_contact_rate: f32,
+
+    // Infection - rates per year, transmission_per_act per sexual contact
+    pub initial_prevalence: f32,
+    pub partner_transmission_rate: f32,
+    pub transmission_per_act: f32,
+    pub household_transmission_rate: f32,
+    pub recovery_rate: f32,
+    pub infection_immunity: bool,  // recovered individuals stay immune (SIR) instead of becoming susceptible again (SIS)
+    pub infection_mortality_rate: f32,
+    pub vertical_transmission_prob: f32,  // infected mother to newborn
+    pub maternal_antibody_prob: f32,  // immune mother passes protective antibodies to newborn
+    pub maternal_antibody_waning_rate: f32,  // 0 for lifelong protection
+
+    // Vaccination - ages and times in years
+    pub routine_vaccinations: Vec<RoutineVaccination>,
+    pub vaccination_campaigns: Vec<VaccinationCampaign>,
+    pub vaccine_efficacy: f32,  // probability a vaccinated susceptible becomes immune
 }
 
 impl Default for SimulationParameters {
@@ -44,29 +149,209 @@ impl Default for SimulationParameters {
             min_partner_seeking_age: 20.0,
             max_partner_seeking_age: 50.0,
             spawn_individual_age: 18.0,
+            homosexual_fraction: 0.0,
+            bisexual_fraction: 0.0,
+            background_mortality_rate: 0.0,
+            initial_sex_ratio: 100.0,
+            sex_ratio_at_birth: 100.0,  // even odds; about 105 in most human populations
+            sex_ratio_by_birth_order: Vec::new(),
+
+            // Density dependence - off by default, rates are scaled by (N/K)^strength when on
+            density_dependence: false,
+            carrying_capacity: 500.0,
+            density_cell_size: 0.0,
+            density_fertility_strength: 1.0,
+            density_mortality_strength: 1.0,
+
+            // Space
+            grid_width: 15.0,
+            grid_height: 15.0,
+            spatial_matching: false,  // global partner market by default
+            partner_search_radius: 3.0,
+
+            // Households
+            leave_home_age: 18.0,
+            elder_coresidence_rate: 0.5, // elders living alone move in with a child
+            custody_rule: CustodyRule::Mother,
+            orphan_age_limit: 18.0, // children below this age are counted as orphans
+
+            // Emigration - closed population by default
+            emigration_rates: Vec::new(),
 
             // Reproduction - rates and durations only
             min_conception_age: 25.0,
             max_conception_age: 35.0,
             conception_rate: 0.5,
+            cohabitation_fertility_ratio: 1.0, // same fertility inside and outside marriage
             gestation_duration: 40.0 / 52.0,
+            gestation_duration_sd: 0.0,
+            pregnancy_loss_hazards: Vec::new(),
+            postpartum_infecundity: 0.0,  // can conceive again right after delivery
+            postpartum_infecundity_sd: 0.0,
+            breastfeeding_duration: 0.0,
+            breastfeeding_infecundity_ratio: 0.0,
+            multiple_birth_rates: Vec::new(),  // singletons only
+            parity_fertility_ratios: Vec::new(),
+            desired_family_size: 0.0,
+            desired_family_size_sd: 0.0,
+
+            // Childbirth - no excess mortality by default
+            maternal_mortality_prob: 0.0,
+            maternal_mortality_age_ratio: 1.0,
+            maternal_mortality_parity_ratio: 1.0,
+            stillbirth_prob: 0.0,
+            neonatal_mortality_prob: 0.0,
+            infant_mortality_rate: 0.0,
 
             // Relationships - rates only
             breakup_rate: 0.1, // low rate for relationship stability
+            marriage_rate: 0.2,
+            divorce_rate: 0.05,
+
+            // Re-entry - immediate by default
+            breakup_reentry_delay: 0.0,
+            widowhood_reentry_delay: 0.0,
+            reentry_delay_age_factor: 0.0, // extra years of delay per year of age above min_partner_seeking_age
+            no_repartnering_prob: 0.0,
+
+            // Concurrent partnerships - off by default (one steady partner at a time)
+            concurrent_partnerships: false,
+            max_concurrent_partners: 3,  // steady + casual
+            casual_formation_rate: 1.0,
+            casual_dissolution_rate: 2.0,
+            steady_contact_rate: 52.0,  // acts per year
+            casual_contact_rate: 12.0,
+
+            // Infection - no disease unless seeded
+            initial_prevalence: 0.0,
+            partner_transmission_rate: 0.0,
+            transmission_per_act: 0.0,
+            household_transmission_rate: 0.0,
+            recovery_rate: 0.0,
+            infection_immunity: true,
+            infection_mortality_rate: 0.0,
+            vertical_transmission_prob: 0.0,
+            maternal_antibody_prob: 0.0,
+            maternal_antibody_waning_rate: 2.0,  // about six months of protection
+
+            // Vaccination - none by default
+            routine_vaccinations: Vec::new(),
+            vaccination_campaigns: Vec::new(),
+            vaccine_efficacy: 1.0,
         }
     }
 }
 
+impl SimulationParameters {
+    // Set a numeric parameter by its field name, e.g. for per-patch overrides.
+    // Flags take any non-zero value as true, counts are rounded down.
+    pub fn set_by_name(&mut self, name: &str, value: f32) -> Result<(), String> {
+        match name {
+            "death_age" => self.death_age = value,
+            "min_partner_seeking_age" => self.min_partner_seeking_age = value,
+            "max_partner_seeking_age" => self.max_partner_seeking_age = value,
+            "spawn_individual_age" => self.spawn_individual_age = value,
+            "homosexual_fraction" => self.homosexual_fraction = value,
+            "bisexual_fraction" => self.bisexual_fraction = value,
+            "background_mortality_rate" => self.background_mortality_rate = value,
+            "initial_sex_ratio" => self.initial_sex_ratio = value,
+            "sex_ratio_at_birth" => self.sex_ratio_at_birth = value,
+            "carrying_capacity" => self.carrying_capacity = value,
+            "density_cell_size" => self.density_cell_size = value,
+            "density_fertility_strength" => self.density_fertility_strength = value,
+            "density_mortality_strength" => self.density_mortality_strength = value,
+            "grid_width" => self.grid_width = value,
+            "grid_height" => self.grid_height = value,
+            "partner_search_radius" => self.partner_search_radius = value,
+            "leave_home_age" => self.leave_home_age = value,
+            "elder_coresidence_rate" => self.elder_coresidence_rate = value,
+            "orphan_age_limit" => self.orphan_age_limit = value,
+            "min_conception_age" => self.min_conception_age = value,
+            "max_conception_age" => self.max_conception_age = value,
+            "conception_rate" => self.conception_rate = value,
+            "cohabitation_fertility_ratio" => self.cohabitation_fertility_ratio = value,
+            "gestation_duration" => self.gestation_duration = value,
+            "gestation_duration_sd" => self.gestation_duration_sd = value,
+            "postpartum_infecundity" => self.postpartum_infecundity = value,
+            "postpartum_infecundity_sd" => self.postpartum_infecundity_sd = value,
+            "breastfeeding_duration" => self.breastfeeding_duration = value,
+            "breastfeeding_infecundity_ratio" => self.breastfeeding_infecundity_ratio = value,
+            "desired_family_size" => self.desired_family_size = value,
+            "desired_family_size_sd" => self.desired_family_size_sd = value,
+            "maternal_mortality_prob" => self.maternal_mortality_prob = value,
+            "maternal_mortality_age_ratio" => self.maternal_mortality_age_ratio = value,
+            "maternal_mortality_parity_ratio" => self.maternal_mortality_parity_ratio = value,
+            "stillbirth_prob" => self.stillbirth_prob = value,
+            "neonatal_mortality_prob" => self.neonatal_mortality_prob = value,
+            "infant_mortality_rate" => self.infant_mortality_rate = value,
+            "breakup_rate" => self.breakup_rate = value,
+            "marriage_rate" => self.marriage_rate = value,
+            "divorce_rate" => self.divorce_rate = value,
+            "breakup_reentry_delay" => self.breakup_reentry_delay = value,
+            "widowhood_reentry_delay" => self.widowhood_reentry_delay = value,
+            "reentry_delay_age_factor" => self.reentry_delay_age_factor = value,
+            "no_repartnering_prob" => self.no_repartnering_prob = value,
+            "casual_formation_rate" => self.casual_formation_rate = value,
+            "casual_dissolution_rate" => self.casual_dissolution_rate = value,
+            "steady_contact_rate" => self.steady_contact_rate = value,
+            "casual_contact_rate" => self.casual_contact_rate = value,
+            "initial_prevalence" => self.initial_prevalence = value,
+            "partner_transmission_rate" => self.partner_transmission_rate = value,
+            "transmission_per_act" => self.transmission_per_act = value,
+            "household_transmission_rate" => self.household_transmission_rate = value,
+            "recovery_rate" => self.recovery_rate = value,
+            "infection_mortality_rate" => self.infection_mortality_rate = value,
+            "vertical_transmission_prob" => self.vertical_transmission_prob = value,
+            "maternal_antibody_prob" => self.maternal_antibody_prob = value,
+            "maternal_antibody_waning_rate" => self.maternal_antibody_waning_rate = value,
+            "vaccine_efficacy" => self.vaccine_efficacy = value,
+            "spatial_matching" => self.spatial_matching = value != 0.0,
+            "density_dependence" => self.density_dependence = value != 0.0,
+            "concurrent_partnerships" => self.concurrent_partnerships = value != 0.0,
+            "infection_immunity" => self.infection_immunity = value != 0.0,
+            "max_concurrent_partners" => self.max_concurrent_partners = value.max(0.0) as usize,
+            _ => return Err(format!("Unknown or non-numeric parameter '{}'", name)),
+        }
+        Ok(())
+    }
+
+    // Check constraints between parameters, which set_by_name can't enforce one value at a time
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, fraction) in [("homosexual_fraction", self.homosexual_fraction), ("bisexual_fraction", self.bisexual_fraction)] {
+            if !(0.0..=1.0).contains(&fraction) {
+                return Err(format!("{} must be between 0 and 1, got {}", name, fraction));
+            }
+        }
+        if self.homosexual_fraction + self.bisexual_fraction > 1.0 {
+            return Err(format!("homosexual_fraction + bisexual_fraction must not exceed 1, got {}",
+                               self.homosexual_fraction + self.bisexual_fraction));
+        }
+        Ok(())
+    }
+}
+
 pub struct ConfigPlugin;
 
 impl Plugin for ConfigPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<SimulationParameters>()
+            .add_systems(Startup, check_parameters)
             .add_systems(Update, check_simulation_end);
     }
 }
 
+// Stop before the first step rather than run with inconsistent parameters
+pub fn check_parameters(
+    params: Res<SimulationParameters>,
+    mut exit: EventWriter<AppExit>
+) {
+    if let Err(e) = params.validate() {
+        error!("Invalid simulation parameters: {}", e);
+        exit.send(AppExit);
+    }
+}
+
 pub fn check_simulation_end(
     args: Res<Args>,
     time: Res<Time>,
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::individual::Individual;
+use crate::location::Location;
+use crate::config::SimulationParameters;
+
+pub struct DensityPlugin;
+
+impl Plugin for DensityPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PopulationDensity>()
+
+        //-- DENSITY
+        // Counted before Update so all rates in a frame see the same population
+        .add_systems(PreUpdate, update_population_density);
+    }
+}
+
+// ------ DENSITY ------
+
+// Current population size, overall and per cell of side density_cell_size
+#[derive(Resource, Default, Clone, Debug)]
+pub struct PopulationDensity {
+    pub total: usize,
+    pub cell_size: f32,
+    pub cells: HashMap<(i32, i32), usize>,
+}
+
+impl PopulationDensity {
+    fn cell(&self, location: &Location) -> (i32, i32) {
+        ((location.0.x / self.cell_size).floor() as i32, (location.0.y / self.cell_size).floor() as i32)
+    }
+
+    // Population relative to the carrying capacity around `location`, 1.0 without density dependence.
+    // Density is counted over the whole grid, so the capacity is shared by all patches
+    pub fn relative_density(&self, location: Option<&Location>, params: &SimulationParameters) -> f32 {
+        if !params.density_dependence || params.carrying_capacity <= 0.0 {
+            return 1.0;
+        }
+        let (count, capacity) = match location {
+            Some(location) if self.cell_size > 0.0 => {
+                // The capacity is spread evenly over the grid
+                let cell_share = self.cell_size * self.cell_size / (params.grid_width * params.grid_height);
+                (self.cells.get(&self.cell(location)).copied().unwrap_or(0), params.carrying_capacity * cell_share)
+            }
+            _ => (self.total, params.carrying_capacity),
+        };
+        count.max(1) as f32 / capacity
+    }
+
+    // Multiplier on conception rates, 2 / (1 + (N/K)^strength): 1 at capacity, falling towards 0 when
+    // crowded and bounded by 2 when sparse so conception rates stay finite in empty cells
+    pub fn fertility_factor(&self, location: Option<&Location>, params: &SimulationParameters) -> f32 {
+        2.0 / (1.0 + self.relative_density(location, params).powf(params.density_fertility_strength))
+    }
+
+    // Multiplier on the background mortality rate, (N/K)^strength
+    pub fn mortality_factor(&self, location: Option<&Location>, params: &SimulationParameters) -> f32 {
+        self.relative_density(location, params).powf(params.density_mortality_strength)
+    }
+}
+
+pub fn update_population_density(
+    mut density: ResMut<PopulationDensity>,
+    query: Query<Option<&Location>, With<Individual>>,
+    params: Res<SimulationParameters>
+) {
+    if !params.density_dependence {
+        return;
+    }
+
+    density.total = query.iter().count();
+    density.cell_size = params.density_cell_size;
+    density.cells.clear();
+    if density.cell_size > 0.0 {
+        for location in query.iter().flatten() {
+            let cell = density.cell(location);
+            *density.cells.entry(cell).or_default() += 1;
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use rand::prelude::random;
+
+use serde::Serialize;
+
+use crate::individual::{Demog, Sex, Dying};
+use crate::partner::{Partner, Married, Couple, Relationship, UnionType, delay_reentry, detect_widows};
+use crate::location::Location;
+use crate::patch::{InPatch, LocalParameters};
+
+// Emitted when an individual leaves the simulated population alive; not a death
+#[derive(Event, Serialize, Clone)]
+pub struct EmigrationEvent {
+    pub entity: Entity,
+    pub age: f32,
+    pub sex: Sex,
+    pub location: Option<[f32; 2]>,
+    pub patch: Option<usize>,
+    pub time: f32,
+}
+
+pub struct EmigrationPlugin;
+
+impl Plugin for EmigrationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<EmigrationEvent>()
+
+        //-- EMIGRATION
+        // After Update so no system still has commands queued for the emigrant
+        .add_systems(PostUpdate, emigrate
+            .before(detect_widows)
+            .run_if(on_timer(Duration::from_secs_f32(EMIGRATION_TIMESTEP))));
+    }
+}
+
+//-- EMIGRATION
+const EMIGRATION_TIMESTEP: f32 = 1.0/12.0;
+
+// ------ EMIGRATION ------
+
+// Annual emigration rates by sex for ages in [min_age, max_age)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EmigrationRate {
+    pub min_age: f32,
+    pub max_age: f32,
+    pub female: f32,
+    pub male: f32,
+}
+
+// Rate of the first age band containing `age`, zero outside all bands
+pub fn emigration_rate(schedule: &[EmigrationRate], age: f32, sex: Sex) -> f32 {
+    schedule.iter()
+        .find(|band| age >= band.min_age && age < band.max_age)
+        .map(|band| match sex {
+            Sex::Female => band.female,
+            Sex::Male => band.male,
+        })
+        .unwrap_or(0.0)
+}
+
+// Someone who may leave the population, with where they live
+type PotentialEmigrant = (Entity, &'static Demog, Option<&'static Location>, Option<&'static InPatch>);
+
+pub fn emigrate(
+    mut commands: Commands,
+    query: Query<PotentialEmigrant, Without<Dying>>,
+    rel_query: Query<(Entity, &Couple), With<Relationship>>,
+    mut emigration_events: EventWriter<EmigrationEvent>,
+    params: LocalParameters,
+    time: Res<Time>
+) {
+    let mut emigrants = Vec::new();
+    for (e, demog, location_opt, patch_opt) in query.iter() {
+        let params = params.get(patch_opt);
+        let rate = emigration_rate(&params.emigration_rates, demog.age, demog.sex);
+        let emigration_prob = 1.0 - (-EMIGRATION_TIMESTEP * rate).exp();
+        if rate > 0.0 && random::<f32>() < emigration_prob {
+            emigrants.push(e);
+            debug!("{:?} emigrated at age {:.1}", e, demog.age);
+            emigration_events.send(EmigrationEvent {
+                entity: e,
+                age: demog.age,
+                sex: demog.sex,
+                location: location_opt.map(|l| l.coords()),
+                patch: patch_opt.map(|p| p.0),
+                time: time.elapsed_seconds(),
+            });
+        }
+    }
+    if emigrants.is_empty() {
+        return;
+    }
+
+    // Steady unions end here so that detect_widows finds no relationship left for the removed Partner;
+    // casual ones are dropped by cleanup_casual_partnerships like after a death
+    let leaving: HashSet<Entity> = emigrants.iter().copied().collect();
+    for (rel_entity, partners) in rel_query.iter() {
+        if partners.union_type == UnionType::Casual {
+            continue;
+        }
+        let left_behind = match (leaving.contains(&partners.e1), leaving.contains(&partners.e2)) {
+            (true, false) => Some(partners.e2),
+            (false, true) => Some(partners.e1),
+            (true, true) => None,  // the couple emigrated together
+            (false, false) => continue,
+        };
+        if let Some((e, demog, _, patch_opt)) = left_behind.and_then(|e| query.get(e).ok()) {
+            debug!("{:?} was left behind by their emigrating partner", e);
+            let params = params.get(patch_opt);
+            commands.entity(e).remove::<(Partner, Married)>();
+            delay_reentry(&mut commands, e, demog.age, params.breakup_reentry_delay, params);
+        }
+        commands.entity(rel_entity).despawn();
+    }
+
+    for e in emigrants {
+        commands.entity(e).despawn();
+    }
+}
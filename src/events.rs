@@ -1,20 +1,48 @@
 use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::Write;
 
-use crate::individual::{BirthEvent, DeathEvent};
-use crate::partner::{BreakupEvent, PartnerEvent, WidowEvent};
+use crate::individual::{BirthEvent, DeathEvent, DeathCause};
+use crate::gestation::{StillbirthEvent, ConceptionEvent, PregnancyEndEvent, PregnancyOutcome, CompletedFertilityEvent};
+use crate::partner::{BreakupEvent, PartnerEvent, WidowEvent, MarriageEvent, ContactEvent};
+use crate::household::CustodyEvent;
+use crate::orphanhood::OrphanhoodEvent;
+use crate::patch::MigrationEvent;
+use crate::emigration::EmigrationEvent;
+use crate::scenario::ParameterChangeEvent;
+use crate::intervention::EnrolmentEvent;
+use crate::infection::InfectionEvent;
+use crate::vaccination::VaccinationEvent;
+use crate::network::export_network;
 use crate::config::Args;
 
 #[derive(Resource, Default, Clone)]
 pub struct EventLog {
     pub births: Vec<BirthEvent>,
     pub deaths: Vec<DeathEvent>,
+    pub stillbirths: Vec<StillbirthEvent>,
+    pub conceptions: Vec<ConceptionEvent>,
+    pub pregnancy_ends: Vec<PregnancyEndEvent>,
+    pub completed_fertility: Vec<CompletedFertilityEvent>,
     pub partnerships: Vec<PartnerEvent>,
+    pub marriages: Vec<MarriageEvent>,
     pub breakups: Vec<BreakupEvent>,
     pub widowings: Vec<WidowEvent>,
+    pub contacts: Vec<ContactEvent>,
+    pub orphanings: Vec<OrphanhoodEvent>,
+    pub custody: Vec<CustodyEvent>,
+    pub migrations: Vec<MigrationEvent>,
+    pub emigrations: Vec<EmigrationEvent>,
+    pub parameter_changes: Vec<ParameterChangeEvent>,
+    pub enrolments: Vec<EnrolmentEvent>,
+    pub infections: Vec<InfectionEvent>,
+    pub vaccinations: Vec<VaccinationEvent>,
 }
 
+// Width in years of the birth cohorts in the completed fertility summary
+const COHORT_WIDTH: f32 = 10.0;
+
 pub struct EventLogPlugin;
 
 impl Plugin for EventLogPlugin {
@@ -24,9 +52,23 @@ impl Plugin for EventLogPlugin {
             .add_systems(Update, (
                 log_birth_events,
                 log_death_events,
+                log_stillbirth_events,
+                log_conception_events,
+                log_pregnancy_end_events,
+                log_completed_fertility_events,
                 log_partner_events,
+                log_marriage_events,
                 log_breakup_events,
                 log_widow_events,
+                log_contact_events,
+                log_orphanhood_events,
+                log_custody_events,
+                log_migration_events,
+                log_emigration_events,
+                log_parameter_change_events,
+                log_enrolment_events,
+                log_infection_events,
+                log_vaccination_events,
             ))
             .add_systems(bevy::app::Last, print_event_summary);
     }
@@ -40,17 +82,121 @@ fn print_event_summary(
     if !exit_events.is_empty() {
         info!("\n========== EVENT SUMMARY ==========");
         info!("Births:       {}", event_log.births.len());
+        if let Some(interval) = mean_birth_interval(&event_log.births) {
+            info!("  {:<12}{:.2}", "interval", interval);
+        }
+        for (children, count) in deliveries_by_size(&event_log.births).iter().skip(1) {
+            info!("  {:<12}{}", format!("{}-fold", children), count);
+        }
         info!("Deaths:       {}", event_log.deaths.len());
+        for (cause, count) in deaths_by_cause(&event_log.deaths) {
+            info!("  {:<12}{}", cause.name(), count);
+        }
+        info!("Stillbirths:  {}", event_log.stillbirths.len());
+        info!("Conceptions:  {}", event_log.conceptions.len());
+        info!("Pregnancy losses: {}", event_log.pregnancy_ends.iter().filter(|p| p.outcome == PregnancyOutcome::Miscarriage).count());
+        info!("Completed fertility: {} women", event_log.completed_fertility.len());
+        for (cohort, counts) in cohort_fertility(&event_log.completed_fertility, COHORT_WIDTH) {
+            let women: usize = counts.iter().sum();
+            let mean = counts.iter().enumerate().map(|(parity, n)| parity * n).sum::<usize>() as f32 / women as f32;
+            info!("  born {:>6.0}: mean {:.2}, by parity {:?}", cohort, mean, counts);
+        }
         info!("Partnerships: {}", event_log.partnerships.len());
+        info!("Marriages:    {}", event_log.marriages.len());
         info!("Breakups:     {}", event_log.breakups.len());
         info!("Widowings:    {}", event_log.widowings.len());
+        info!("Contacts:     {}", event_log.contacts.len());
+        info!("Orphanings:   {}", event_log.orphanings.len());
+        info!("Custody:      {}", event_log.custody.len());
+        info!("Migrations:   {}", event_log.migrations.len());
+        info!("Emigrations:  {}", event_log.emigrations.len());
+        info!("Parameter changes: {}", event_log.parameter_changes.len());
+        info!("Enrolments:   {}", event_log.enrolments.len());
+        info!("Infections:   {}", event_log.infections.len());
+        info!("Vaccinations: {}", event_log.vaccinations.len());
         info!("===================================\n");
 
         // Export to JSON if requested
         if args.export_events {
             export_births_json(&event_log);
         }
+        if args.export_network {
+            export_network(&event_log, "network");
+        }
+    }
+}
+
+// Death counts per cause, in order of first occurrence
+pub fn deaths_by_cause(deaths: &[DeathEvent]) -> Vec<(DeathCause, usize)> {
+    let mut counts: Vec<(DeathCause, usize)> = Vec::new();
+    for death in deaths.iter() {
+        match counts.iter_mut().find(|(cause, _)| *cause == death.cause) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((death.cause, 1)),
+        }
+    }
+    counts
+}
+
+// Completed parity distributions by birth cohort, oldest cohort first.
+// Each entry holds the cohort's start time and the number of women by parity 0, 1, 2, ...
+pub fn cohort_fertility(records: &[CompletedFertilityEvent], cohort_width: f32) -> Vec<(f32, Vec<usize>)> {
+    let mut cohorts: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+    for record in records.iter() {
+        let counts = cohorts.entry((record.birth_time / cohort_width).floor() as i32).or_default();
+        if counts.len() <= record.parity {
+            counts.resize(record.parity + 1, 0);
+        }
+        counts[record.parity] += 1;
+    }
+    cohorts.into_iter()
+        .map(|(cohort, counts)| (cohort as f32 * cohort_width, counts))
+        .collect()
+}
+
+// Mean time between consecutive deliveries to the same mother, None before any second delivery.
+// Children of a multiple birth, sharing a pregnancy_id or else a birth time, count as one delivery.
+pub fn mean_birth_interval(births: &[BirthEvent]) -> Option<f32> {
+    let mut last_delivery: HashMap<Entity, (Option<u32>, f32)> = HashMap::new();
+    let mut intervals = Vec::new();
+    for birth in births.iter() {
+        let Some(mother) = birth.mother_entity else {
+            continue;
+        };
+        if let Some((pregnancy_id, time)) = last_delivery.insert(mother, (birth.pregnancy_id, birth.time)) {
+            let same_delivery = match (pregnancy_id, birth.pregnancy_id) {
+                (Some(previous), Some(current)) => previous == current,
+                _ => time == birth.time,
+            };
+            if !same_delivery {
+                intervals.push(birth.time - time);
+            }
+        }
+    }
+    if intervals.is_empty() {
+        return None;
+    }
+    Some(intervals.iter().sum::<f32>() / intervals.len() as f32)
+}
+
+// Deliveries by number of children born together, from 1 (singletons) up.
+// Founders are left out, other births without a pregnancy_id count as singletons.
+pub fn deliveries_by_size(births: &[BirthEvent]) -> Vec<(usize, usize)> {
+    let mut sizes: HashMap<u32, usize> = HashMap::new();
+    let mut singletons = 0;
+    for birth in births.iter().filter(|b| b.mother_entity.is_some()) {
+        match birth.pregnancy_id {
+            Some(id) => *sizes.entry(id).or_default() += 1,
+            None => singletons += 1,
+        }
     }
+    let max_size = sizes.values().copied().max().unwrap_or(1);
+    let mut counts: Vec<(usize, usize)> = (1..=max_size).map(|size| (size, 0)).collect();
+    counts[0].1 = singletons;
+    for size in sizes.into_values() {
+        counts[size - 1].1 += 1;
+    }
+    counts
 }
 
 fn export_births_json(event_log: &EventLog) {
@@ -76,11 +222,16 @@ fn log_birth_events(
     mut events: EventReader<BirthEvent>
 ) {
     for event in events.read() {
-        info!("EVENT: Birth of {:?} (mother: {:?}) at time {:.2}",
-            event.child_entity, event.mother_entity, event.time);
+        info!("EVENT: Birth of {:?} (mother: {:?}, father: {:?}) at time {:.2}",
+            event.child_entity, event.mother_entity, event.father_entity, event.time);
         event_log.births.push(BirthEvent {
             child_entity: event.child_entity,
             mother_entity: event.mother_entity,
+            father_entity: event.father_entity,
+            pregnancy_id: event.pregnancy_id,
+            sex: event.sex,
+            maternal_transfer: event.maternal_transfer.clone(),
+            location: event.location,
             time: event.time,
         });
     }
@@ -91,11 +242,81 @@ fn log_death_events(
     mut events: EventReader<DeathEvent>
 ) {
     for event in events.read() {
-        info!("EVENT: Death of {:?} at age {:.2}, time {:.2}",
-            event.entity, event.age, event.time);
+        info!("EVENT: Death of {:?} at age {:.2} ({}), time {:.2}",
+            event.entity, event.age, event.cause.name(), event.time);
         event_log.deaths.push(DeathEvent {
             entity: event.entity,
             age: event.age,
+            cause: event.cause,
+            location: event.location,
+            time: event.time,
+        });
+    }
+}
+
+fn log_stillbirth_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<StillbirthEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Stillbirth to {:?} (father: {:?}) at time {:.2}",
+            event.mother_entity, event.father_entity, event.time);
+        event_log.stillbirths.push(StillbirthEvent {
+            mother_entity: event.mother_entity,
+            father_entity: event.father_entity,
+            location: event.location,
+            time: event.time,
+        });
+    }
+}
+
+fn log_conception_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<ConceptionEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Conception by {:?} (father: {:?}) at age {:.2}, time {:.2}",
+            event.mother_entity, event.father_entity, event.age, event.time);
+        event_log.conceptions.push(ConceptionEvent {
+            pregnancy_id: event.pregnancy_id,
+            mother_entity: event.mother_entity,
+            father_entity: event.father_entity,
+            age: event.age,
+            gestation_duration: event.gestation_duration,
+            time: event.time,
+        });
+    }
+}
+
+fn log_pregnancy_end_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<PregnancyEndEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Pregnancy of {:?} ended in {:?} after {:.2}, time {:.2}",
+            event.mother_entity, event.outcome, event.gestational_age, event.time);
+        event_log.pregnancy_ends.push(PregnancyEndEvent {
+            pregnancy_id: event.pregnancy_id,
+            mother_entity: event.mother_entity,
+            father_entity: event.father_entity,
+            outcome: event.outcome,
+            gestational_age: event.gestational_age,
+            time: event.time,
+        });
+    }
+}
+
+fn log_completed_fertility_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<CompletedFertilityEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Completed fertility of {:?} with parity {} at time {:.2}",
+            event.entity, event.parity, event.time);
+        event_log.completed_fertility.push(CompletedFertilityEvent {
+            entity: event.entity,
+            birth_time: event.birth_time,
+            parity: event.parity,
             time: event.time,
         });
     }
@@ -106,9 +327,28 @@ fn log_partner_events(
     mut events: EventReader<PartnerEvent>
 ) {
     for event in events.read() {
-        info!("EVENT: Partnership between {:?} and {:?} (rel: {:?}) at time {:.2}",
-            event.individual1, event.individual2, event.relationship_entity, event.time);
+        info!("EVENT: {:?} partnership between {:?} and {:?} (rel: {:?}) at time {:.2}",
+            event.union_type, event.individual1, event.individual2, event.relationship_entity, event.time);
         event_log.partnerships.push(PartnerEvent {
+            individual1: event.individual1,
+            individual2: event.individual2,
+            relationship_entity: event.relationship_entity,
+            union_type: event.union_type,
+            location: event.location,
+            distance: event.distance,
+            time: event.time,
+        });
+    }
+}
+
+fn log_marriage_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<MarriageEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Marriage between {:?} and {:?} (rel: {:?}) at time {:.2}",
+            event.individual1, event.individual2, event.relationship_entity, event.time);
+        event_log.marriages.push(MarriageEvent {
             individual1: event.individual1,
             individual2: event.individual2,
             relationship_entity: event.relationship_entity,
@@ -122,12 +362,14 @@ fn log_breakup_events(
     mut events: EventReader<BreakupEvent>
 ) {
     for event in events.read() {
-        info!("EVENT: Breakup (male: {:?}, female: {:?}, rel: {:?}) at time {:.2}",
-            event.male_entity, event._female_entity, event.relationship_entity, event.time);
+        info!("EVENT: {:?} breakup between {:?} and {:?} (rel: {:?}) at time {:.2}",
+            event.union_type, event.partner1, event.partner2, event.relationship_entity, event.time);
         event_log.breakups.push(BreakupEvent {
-            male_entity: event.male_entity,
-            _female_entity: event._female_entity,
+            partner1: event.partner1,
+            partner2: event.partner2,
             relationship_entity: event.relationship_entity,
+            union_type: event.union_type,
+            location: event.location,
             time: event.time,
         });
     }
@@ -148,3 +390,154 @@ fn log_widow_events(
         });
     }
 }
+
+fn log_contact_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<ContactEvent>
+) {
+    for event in events.read() {
+        debug!("EVENT: Contact between {:?} and {:?} (rel: {:?}) at time {:.2}",
+            event.individual1, event.individual2, event.relationship_entity, event.time);
+        event_log.contacts.push(ContactEvent {
+            individual1: event.individual1,
+            individual2: event.individual2,
+            relationship_entity: event.relationship_entity,
+            union_type: event.union_type,
+            time: event.time,
+        });
+    }
+}
+
+fn log_orphanhood_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<OrphanhoodEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: {:?} orphanhood of {:?} at age {:.2} (deceased: {:?}) at time {:.2}",
+            event.kind, event.child_entity, event.child_age, event.deceased_parent, event.time);
+        event_log.orphanings.push(OrphanhoodEvent {
+            child_entity: event.child_entity,
+            deceased_parent: event.deceased_parent,
+            kind: event.kind,
+            child_age: event.child_age,
+            time: event.time,
+        });
+    }
+}
+
+fn log_custody_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<CustodyEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Custody of {:?} assigned to {:?} (rel: {:?}) at time {:.2}",
+            event.child_entity, event.custodian, event.relationship_entity, event.time);
+        event_log.custody.push(CustodyEvent {
+            child_entity: event.child_entity,
+            custodian: event.custodian,
+            relationship_entity: event.relationship_entity,
+            time: event.time,
+        });
+    }
+}
+
+fn log_migration_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<MigrationEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Migration of {:?} from patch {} to patch {} at time {:.2}",
+            event.entity, event.from_patch, event.to_patch, event.time);
+        event_log.migrations.push(MigrationEvent {
+            entity: event.entity,
+            household: event.household,
+            from_patch: event.from_patch,
+            to_patch: event.to_patch,
+            location: event.location,
+            time: event.time,
+        });
+    }
+}
+
+fn log_emigration_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<EmigrationEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Emigration of {:?} at age {:.2}, time {:.2}",
+            event.entity, event.age, event.time);
+        event_log.emigrations.push(EmigrationEvent {
+            entity: event.entity,
+            age: event.age,
+            sex: event.sex,
+            location: event.location,
+            patch: event.patch,
+            time: event.time,
+        });
+    }
+}
+
+fn log_parameter_change_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<ParameterChangeEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Parameter {} set to {} at time {:.2}",
+            event.parameter, event.value, event.time);
+        event_log.parameter_changes.push(ParameterChangeEvent {
+            parameter: event.parameter.clone(),
+            value: event.value,
+            time: event.time,
+        });
+    }
+}
+
+fn log_enrolment_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<EnrolmentEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Enrolment of {:?} in {} at age {:.2}, time {:.2}",
+            event.entity, event.intervention, event.age, event.time);
+        event_log.enrolments.push(EnrolmentEvent {
+            entity: event.entity,
+            intervention: event.intervention.clone(),
+            age: event.age,
+            time: event.time,
+        });
+    }
+}
+
+fn log_infection_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<InfectionEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: Infection of {:?} by {:?} via {:?} at age {:.2}, time {:.2}",
+            event.entity, event.source, event.route, event.age, event.time);
+        event_log.infections.push(InfectionEvent {
+            entity: event.entity,
+            source: event.source,
+            route: event.route,
+            age: event.age,
+            time: event.time,
+        });
+    }
+}
+
+fn log_vaccination_events(
+    mut event_log: ResMut<EventLog>,
+    mut events: EventReader<VaccinationEvent>
+) {
+    for event in events.read() {
+        info!("EVENT: {:?} vaccination of {:?} at age {:.2}, time {:.2}",
+            event.strategy, event.entity, event.age, event.time);
+        event_log.vaccinations.push(VaccinationEvent {
+            entity: event.entity,
+            strategy: event.strategy,
+            age: event.age,
+            protected: event.protected,
+            time: event.time,
+        });
+    }
+}
@@ -1,29 +1,87 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
+use bevy::ecs::system::{EntityCommands, SystemParam};
 use bevy::time::common_conditions::on_timer;
 use std::time::Duration;
 
 use rand::prelude::random;
 
+use serde::Serialize;
+
 use crate::individual::{
-    Demog, Sex, spawn_individual, BirthEvent
+    Demog, Sex, spawn_individual, remove_dead, BirthEvent, BirthOrigin, DeathCause, Dying, MaternalTransfer
 };
-use crate::partner::Partner;
+use crate::infection::DiseaseState;
+use crate::partner::{Partner, Married};
+use crate::location::{Location, NEWBORN_SPREAD};
+use crate::patch::{InPatch, LocalParameters};
+use crate::density::PopulationDensity;
+use crate::emigration::{EmigrationEvent, emigrate};
+use crate::intervention::HazardModifier;
 use crate::config::SimulationParameters;
 
+#[derive(Event, Serialize, Clone)]
+pub struct ConceptionEvent {
+    pub pregnancy_id: u32,
+    pub mother_entity: Entity,
+    pub father_entity: Entity,
+    pub age: f32,
+    pub gestation_duration: f32,  // sampled length, if carried to term
+    pub time: f32,
+}
+
+#[derive(Event, Serialize, Clone)]
+pub struct PregnancyEndEvent {
+    pub pregnancy_id: Option<u32>,  // None for pregnancies set up without a Pregnancy component
+    pub mother_entity: Entity,
+    pub father_entity: Option<Entity>,
+    pub outcome: PregnancyOutcome,
+    pub gestational_age: f32,
+    pub time: f32,
+}
+
+// Sent once per woman when she passes max_conception_age
+#[derive(Event, Serialize, Clone)]
+pub struct CompletedFertilityEvent {
+    pub entity: Entity,
+    pub birth_time: f32,  // negative for founders born before the run
+    pub parity: usize,
+    pub time: f32,
+}
+
+#[derive(Event, Serialize, Clone)]
+pub struct StillbirthEvent {
+    pub mother_entity: Entity,
+    pub father_entity: Option<Entity>,
+    pub location: Option<[f32; 2]>,
+    pub time: f32,
+}
+
 pub struct GestationPlugin;
 
 impl Plugin for GestationPlugin {
     fn build(&self, app: &mut App) {
         app
+            .add_event::<ConceptionEvent>()
+            .add_event::<PregnancyEndEvent>()
+            .add_event::<StillbirthEvent>()
+            .add_event::<CompletedFertilityEvent>()
+            .init_resource::<MaternalTransferRules>()
 
         //-- GESTATION
         .add_systems(Update, (
             immaculate_conception,
             (
-                conception,
-                update_gestation,
+                (update_postpartum_infecundity, conception, log_conceptions).chain(),
+                (update_gestation, maternal_mortality).chain(),
+                complete_fertility,
             ).run_if(on_timer(Duration::from_secs_f32(CONCEPTION_TIMESTEP))),
-        ));
+        ))
+        // Emigrants are still there to be looked up until emigrate's despawns are applied
+        .add_systems(PostUpdate, end_interrupted_pregnancies
+            .after_ignore_deferred(emigrate)
+            .before(remove_dead));
     }
 }
 
@@ -31,34 +89,429 @@ impl Plugin for GestationPlugin {
 const CONCEPTION_TIMESTEP: f32 = 1.0/52.0;
 // MIN_CONCEPTION_AGE, MAX_CONCEPTION_AGE, CONCEPTION_RATE, GESTATION_DURATION now come from SimulationParameters
 
+//-- CHILDBIRTH
+// Maternal risk is lowest for mothers aged [LOW_RISK_MIN_AGE, LOW_RISK_MAX_AGE) with 1 to LOW_RISK_MAX_PARITY previous births
+const LOW_RISK_MIN_AGE: f32 = 20.0;
+const LOW_RISK_MAX_AGE: f32 = 35.0;
+const LOW_RISK_MAX_PARITY: usize = 3;
+
 // ------ GESTATION ------
 
 #[derive(Component)]
 pub struct RemainingGestation(pub f32);
 
+// Details of the current pregnancy carried alongside RemainingGestation
+#[derive(Component)]
+pub struct Pregnancy {
+    pub id: u32,  // unique per conception, shared by the children of a multiple birth
+    pub father: Entity,
+    pub duration: f32,  // sampled gestation length
+}
+
+// Children a woman has given birth to alive
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub struct Parity(pub usize);
+
+// Number of children after which a woman stops conceiving, drawn once when she first could conceive
+#[derive(Component)]
+pub struct DesiredFamilySize(pub usize);
+
+// Marks women whose completed fertility has been recorded
+#[derive(Component)]
+pub struct FertilityCompleted;
+
+// Years left before a woman who has delivered can conceive again
+#[derive(Component)]
+pub struct PostpartumInfecundity(pub f32);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub enum PregnancyOutcome {
+    LiveBirth,
+    Stillbirth,
+    Miscarriage,  // loss before delivery
+    MaternalDeath,  // the mother died while pregnant, of any cause
+    Censored,  // the mother emigrated while pregnant, outcome unobserved
+}
+
+// Outcomes of pregnancies that run their course, interrupted ones end in end_interrupted_pregnancies
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Termination {
+    LiveBirth,
+    Stillbirth,
+    Miscarriage,
+}
+
+impl From<Termination> for PregnancyOutcome {
+    fn from(termination: Termination) -> Self {
+        match termination {
+            Termination::LiveBirth => PregnancyOutcome::LiveBirth,
+            Termination::Stillbirth => PregnancyOutcome::Stillbirth,
+            Termination::Miscarriage => PregnancyOutcome::Miscarriage,
+        }
+    }
+}
+
+// Annual hazard of pregnancy loss for gestational ages in [min_gestation, max_gestation), in years
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PregnancyLossHazard {
+    pub min_gestation: f32,
+    pub max_gestation: f32,
+    pub rate: f32,
+}
+
+// Rate of the first band containing `gestational_age`, zero outside all bands
+pub fn pregnancy_loss_rate(schedule: &[PregnancyLossHazard], gestational_age: f32) -> f32 {
+    schedule.iter()
+        .find(|band| gestational_age >= band.min_gestation && gestational_age < band.max_gestation)
+        .map_or(0.0, |band| band.rate)
+}
+
+// Probabilities of twins and triplets per delivery for mothers aged [min_age, max_age)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MultipleBirthRate {
+    pub min_age: f32,
+    pub max_age: f32,
+    pub twins: f32,
+    pub triplets: f32,
+}
+
+// Number of children in a live birth, one outside all age bands
+pub fn draw_birth_count(schedule: &[MultipleBirthRate], age: f32) -> usize {
+    let Some(band) = schedule.iter().find(|band| age >= band.min_age && age < band.max_age) else {
+        return 1;
+    };
+    let draw = random::<f32>();
+    if draw < band.triplets {
+        3
+    } else if draw < band.triplets + band.twins {
+        2
+    } else {
+        1
+    }
+}
+
+// Conception rate multiplier for a woman with `parity` children, the last ratio for all higher parities
+pub fn parity_fertility_ratio(parity: usize, params: &SimulationParameters) -> f32 {
+    let ratios = &params.parity_fertility_ratios;
+    ratios.get(parity).or(ratios.last()).copied().unwrap_or(1.0)
+}
+
+// Normal value truncated at 3 standard deviations and at zero; exactly `mean` when sd is 0
+fn sample_truncated_normal(mean: f32, sd: f32) -> f32 {
+    if sd <= 0.0 {
+        return mean;
+    }
+    // Box-Muller transform, with u1 in (0, 1] so the log is finite
+    let u1 = 1.0 - random::<f32>();
+    let u2 = random::<f32>();
+    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos();
+    (mean + sd * z.clamp(-3.0, 3.0)).max(0.0)
+}
+
+pub fn sample_gestation_duration(params: &SimulationParameters) -> f32 {
+    sample_truncated_normal(params.gestation_duration, params.gestation_duration_sd)
+}
+
+// Breastfeeding only follows a live birth and extends the period by breastfeeding_infecundity_ratio of its length
+pub fn sample_postpartum_infecundity(live_birth: bool, params: &SimulationParameters) -> f32 {
+    let breastfeeding = if live_birth { params.breastfeeding_duration } else { 0.0 };
+    let mean = params.postpartum_infecundity + params.breastfeeding_infecundity_ratio * breastfeeding;
+    sample_truncated_normal(mean, params.postpartum_infecundity_sd)
+}
+
+pub fn sample_desired_family_size(params: &SimulationParameters) -> usize {
+    sample_truncated_normal(params.desired_family_size, params.desired_family_size_sd).round() as usize
+}
+
 #[derive(Component)]
 pub struct Mother(pub Entity);
 
+#[derive(Component)]
+pub struct Father(pub Entity);
+
+// Males per 100 females for a child with `parity` older siblings by the same mother.
+// A non-empty sex_ratio_by_birth_order replaces sex_ratio_at_birth, which is then not used at all.
+pub fn sex_ratio_at_birth(parity: usize, params: &SimulationParameters) -> f32 {
+    let by_order = &params.sex_ratio_by_birth_order;
+    by_order.get(parity).or(by_order.last()).copied().unwrap_or(params.sex_ratio_at_birth)
+}
+
+// What a maternal transfer rule sees of the mother at delivery
+pub struct Delivery<'a> {
+    pub mother: Entity,
+    pub demog: &'a Demog,
+    pub disease: Option<&'a DiseaseState>,
+    pub params: &'a SimulationParameters,  // in effect in the mother's patch
+}
+
+// A maternal attribute that may pass to each newborn; register with App::add_maternal_transfer
+pub trait MaternalTransferRule: Send + Sync + 'static {
+    // Recorded in the BirthEvent's maternal_transfer of children who receive it
+    fn attribute(&self) -> &str;
+
+    // Chance that a child of this delivery receives the attribute, drawn independently per child
+    fn probability(&self, delivery: &Delivery) -> f32;
+
+    // Give the attribute to the newborn
+    fn apply(&self, child: &mut EntityCommands);
+}
+
+#[derive(Resource, Default)]
+pub struct MaternalTransferRules(pub Vec<Box<dyn MaternalTransferRule>>);
+
+impl MaternalTransferRules {
+    // Draws every rule for one newborn, later rules applied over earlier ones
+    pub fn draw(&self, delivery: &Delivery) -> MaternalTransfer {
+        MaternalTransfer(self.0.iter()
+            .filter(|rule| random::<f32>() < rule.probability(delivery))
+            .map(|rule| rule.attribute().to_string())
+            .collect())
+    }
+
+    pub fn apply(&self, commands: &mut Commands, child: Entity, transfer: &MaternalTransfer) {
+        for rule in self.0.iter().filter(|rule| transfer.received(rule.attribute())) {
+            rule.apply(&mut commands.entity(child));
+        }
+    }
+}
+
+pub trait AddMaternalTransfer {
+    fn add_maternal_transfer(&mut self, rule: impl MaternalTransferRule) -> &mut Self;
+}
+
+impl AddMaternalTransfer for App {
+    fn add_maternal_transfer(&mut self, rule: impl MaternalTransferRule) -> &mut Self {
+        self.world.get_resource_or_insert_with(MaternalTransferRules::default).0.push(Box::new(rule));
+        self
+    }
+}
+
+// Birth log together with the transfer rules drawn for each newborn
+#[derive(SystemParam)]
+pub struct Births<'w> {
+    pub events: EventWriter<'w, BirthEvent>,
+    pub transfer_rules: Option<Res<'w, MaternalTransferRules>>,
+}
+
+// A pregnant woman and what shapes the delivery: her pregnancy, prior births, disease and patch
+type Expecting = (Entity, &'static mut RemainingGestation, &'static Demog, Option<&'static Pregnancy>, Option<&'static Parity>, Option<&'static Location>, Option<&'static DiseaseState>, Option<&'static InPatch>);
+
 pub fn update_gestation(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut RemainingGestation, &Demog)>,
-    mut birth_events: EventWriter<BirthEvent>,
+    mut query: Query<Expecting>,
+    mut births: Births,
+    mut stillbirth_events: EventWriter<StillbirthEvent>,
+    mut pregnancy_end_events: EventWriter<PregnancyEndEvent>,
+    params: LocalParameters,
     time: Res<Time>
 ) {
-    for (e, mut gestation, demog) in query.iter_mut() {
+    for (e, mut gestation, demog, pregnancy_opt, parity_opt, location_opt, state_opt, patch_opt) in query.iter_mut() {
+        let params = params.get(patch_opt);
         gestation.0 -= CONCEPTION_TIMESTEP;
 
-        if gestation.0 < 0.0 {
-            commands.entity(e).remove::<RemainingGestation>();
-            debug!("{:?} had a baby at age {}!", e, demog.age);
-
-            spawn_individual(
-                &mut commands,
-                0.0,    // age = newborn
-                Some(e), // mother's entity_id
-                &mut birth_events,
-                &time
-            );
+        // Pregnancies without a Pregnancy component are taken to last gestation_duration
+        let gestational_age = pregnancy_opt.map_or(params.gestation_duration, |p| p.duration) - gestation.0;
+        let father_opt = pregnancy_opt.map(|p| p.father);
+        let pregnancy_id = pregnancy_opt.map(|p| p.id);
+
+        let outcome = if gestation.0 < 0.0 {
+            if random::<f32>() < params.stillbirth_prob {
+                Termination::Stillbirth
+            } else {
+                Termination::LiveBirth
+            }
+        } else {
+            let loss_rate = pregnancy_loss_rate(&params.pregnancy_loss_hazards, gestational_age);
+            let loss_prob = 1.0 - (-CONCEPTION_TIMESTEP * loss_rate).exp();
+            if loss_rate > 0.0 && random::<f32>() < loss_prob {
+                Termination::Miscarriage
+            } else {
+                continue;
+            }
+        };
+
+        commands.entity(e).remove::<(RemainingGestation, Pregnancy)>();
+        pregnancy_end_events.send(PregnancyEndEvent {
+            pregnancy_id,
+            mother_entity: e,
+            father_entity: father_opt,
+            outcome: outcome.into(),
+            gestational_age,
+            time: time.elapsed_seconds(),
+        });
+
+        if outcome != Termination::Miscarriage {
+            let infecundity = sample_postpartum_infecundity(outcome == Termination::LiveBirth, params);
+            if infecundity > 0.0 {
+                commands.entity(e).insert(PostpartumInfecundity(infecundity));
+            }
+        }
+
+        match outcome {
+            Termination::Miscarriage => {
+                debug!("{:?} lost a pregnancy at {:.2} years gestation", e, gestational_age);
+            }
+            Termination::Stillbirth => {
+                debug!("{:?} had a stillbirth at age {}", e, demog.age);
+                stillbirth_events.send(StillbirthEvent {
+                    mother_entity: e,
+                    father_entity: father_opt,
+                    location: location_opt.map(|l| l.coords()),
+                    time: time.elapsed_seconds(),
+                });
+            }
+            Termination::LiveBirth => {
+                let children = draw_birth_count(&params.multiple_birth_rates, demog.age);
+                debug!("{:?} had {} baby(ies) at age {}!", e, children, demog.age);
+
+                // Children of a multiple birth share a birth order
+                let parity = parity_opt.map_or(0, |p| p.0);
+                let sex_ratio = sex_ratio_at_birth(parity, params);
+                commands.entity(e).insert(Parity(parity + children));
+
+                // Each child of a multiple birth draws its own sex and maternal transfer
+                let delivery = Delivery { mother: e, demog, disease: state_opt, params };
+                for _ in 0..children {
+                    let maternal_transfer = births.transfer_rules.as_deref()
+                        .map_or_else(MaternalTransfer::default, |rules| rules.draw(&delivery));
+                    let child = spawn_individual(
+                        &mut commands,
+                        0.0,    // age = newborn
+                        Sex::draw(sex_ratio),
+                        location_opt.map_or_else(|| Location::random(params), |l| l.near(NEWBORN_SPREAD, params)),
+                        BirthOrigin {
+                            mother: Some(e),
+                            father: father_opt,
+                            pregnancy_id,
+                            maternal_transfer: maternal_transfer.clone(),
+                        },
+                        &mut births.events,
+                        &time
+                    );
+                    if let Some(rules) = births.transfer_rules.as_deref() {
+                        rules.apply(&mut commands, child, &maternal_transfer);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Probability of dying at delivery given the mother's age and number of previous live births
+pub fn maternal_death_prob(age: f32, parity: usize, params: &SimulationParameters) -> f32 {
+    let mut prob = params.maternal_mortality_prob;
+    if !(LOW_RISK_MIN_AGE..LOW_RISK_MAX_AGE).contains(&age) {
+        prob *= params.maternal_mortality_age_ratio;
+    }
+    if parity == 0 || parity > LOW_RISK_MAX_PARITY {
+        prob *= params.maternal_mortality_parity_ratio;
+    }
+    prob.min(1.0)
+}
+
+// Every delivery, live or stillborn, carries a risk of maternal death
+pub fn maternal_mortality(
+    mut commands: Commands,
+    mother_query: Query<(&Demog, Option<&Parity>, Option<&InPatch>)>,
+    mut birth_events: EventReader<BirthEvent>,
+    mut stillbirth_events: EventReader<StillbirthEvent>,
+    params: LocalParameters
+) {
+    // Children born per mother in this step, so a multiple birth is a single delivery
+    let mut deliveries: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    for birth in birth_events.read() {
+        if let Some(mother) = birth.mother_entity {
+            deliveries.entry(mother).or_default().push(birth.child_entity);
+        }
+    }
+    for stillbirth in stillbirth_events.read() {
+        deliveries.entry(stillbirth.mother_entity).or_default();
+    }
+
+    for (mother, children) in deliveries {
+        let Ok((demog, parity_opt, patch_opt)) = mother_query.get(mother) else {
+            continue;
+        };
+
+        // Parity already includes the children just born
+        let parity = parity_opt.map_or(0, |p| p.0).saturating_sub(children.len());
+
+        if random::<f32>() < maternal_death_prob(demog.age, parity, params.get(patch_opt)) {
+            debug!("{:?} died in childbirth at age {}", mother, demog.age);
+            commands.entity(mother).insert(Dying(DeathCause::Maternal));
+        }
+    }
+}
+
+// Pregnancies of women marked Dying or who emigrated this step end without a delivery
+// A pregnancy and whether its mother is dying
+type OngoingPregnancy = (Entity, &'static RemainingGestation, Option<&'static Pregnancy>, Option<&'static Dying>, Option<&'static InPatch>);
+
+pub fn end_interrupted_pregnancies(
+    query: Query<OngoingPregnancy>,
+    mut emigration_events: EventReader<EmigrationEvent>,
+    mut end_events: EventWriter<PregnancyEndEvent>,
+    params: LocalParameters,
+    time: Res<Time>
+) {
+    let emigrants: Vec<Entity> = emigration_events.read().map(|event| event.entity).collect();
+    for (e, gestation, pregnancy_opt, dying_opt, patch_opt) in query.iter() {
+        let outcome = if dying_opt.is_some() {
+            PregnancyOutcome::MaternalDeath
+        } else if emigrants.contains(&e) {
+            PregnancyOutcome::Censored
+        } else {
+            continue;
+        };
+        let gestational_age = pregnancy_opt.map_or(params.get(patch_opt).gestation_duration, |p| p.duration) - gestation.0;
+        debug!("{:?} pregnancy ended at {:.2} years gestation ({:?})", e, gestational_age, outcome);
+        end_events.send(PregnancyEndEvent {
+            pregnancy_id: pregnancy_opt.map(|p| p.id),
+            mother_entity: e,
+            father_entity: pregnancy_opt.map(|p| p.father),
+            outcome,
+            gestational_age,
+            time: time.elapsed_seconds(),
+        });
+    }
+}
+
+// A woman's births so far and the patch setting her max_conception_age
+type FertilityHistory = (Entity, &'static Demog, Option<&'static Parity>, Option<&'static InPatch>);
+
+// Women still pregnant at max_conception_age are recorded once the pregnancy has ended
+pub fn complete_fertility(
+    mut commands: Commands,
+    query: Query<FertilityHistory, (Without<FertilityCompleted>, Without<RemainingGestation>)>,
+    mut completed_events: EventWriter<CompletedFertilityEvent>,
+    params: LocalParameters,
+    time: Res<Time>
+) {
+    for (e, demog, parity_opt, patch_opt) in query.iter() {
+        let params = params.get(patch_opt);
+        if demog.sex != Sex::Female || demog.age < params.max_conception_age {
+            continue;
+        }
+        let now = time.elapsed_seconds();
+        completed_events.send(CompletedFertilityEvent {
+            entity: e,
+            birth_time: now - demog.age,
+            parity: parity_opt.map_or(0, |p| p.0),
+            time: now,
+        });
+        commands.entity(e).insert(FertilityCompleted);
+    }
+}
+
+pub fn update_postpartum_infecundity(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut PostpartumInfecundity)>
+) {
+    for (e, mut infecundity) in query.iter_mut() {
+        infecundity.0 -= CONCEPTION_TIMESTEP;
+        if infecundity.0 < 0.0 {
+            debug!("{:?} can conceive again", e);
+            commands.entity(e).remove::<PostpartumInfecundity>();
         }
     }
 }
@@ -67,20 +520,75 @@ pub fn immaculate_conception() {
     // TODO: placeholder for fecundity-rate dependent insertion of RemainingGestation w/o relationship
 }
 
+// A partnered woman with everything that scales her conception rate
+type Fecund = (Entity, &'static Demog, &'static Partner, Option<&'static Married>, Option<&'static InPatch>, Option<&'static Location>, Option<&'static HazardModifier>, Option<&'static Parity>, Option<&'static DesiredFamilySize>);
+
 pub fn conception(
     mut commands: Commands,
-    query: Query<(Entity, &Demog, &Partner), Without<RemainingGestation>>,
-    params: Res<SimulationParameters>
+    query: Query<Fecund, (Without<RemainingGestation>, Without<PostpartumInfecundity>)>,
+    partner_query: Query<&Demog>,
+    params: LocalParameters,
+    density: Option<Res<PopulationDensity>>,
+    mut next_pregnancy_id: Local<u32>
 ) {
-    for (e, demog, partner) in query.iter() {
-        if demog.sex == Sex::Female {
+    for (e, demog, partner, married_opt, patch_opt, location_opt, modifier_opt, parity_opt, desired_opt) in query.iter() {
+        let params = params.get(patch_opt);
+        let male_partner = partner_query.get(partner.0).is_ok_and(|d| d.sex == Sex::Male);
+        if demog.sex == Sex::Female && male_partner {
             if demog.age > params.min_conception_age && demog.age < params.max_conception_age {
-                let conception_prob = 1.0 - (-CONCEPTION_TIMESTEP * params.conception_rate).exp(); // f32.exp() is e^(f32)
+                let parity = parity_opt.map_or(0, |p| p.0);
+
+                // Stopping rule: no further conceptions once the desired family size is reached
+                if params.desired_family_size > 0.0 {
+                    let desired = match desired_opt {
+                        Some(desired) => desired.0,
+                        None => {
+                            let desired = sample_desired_family_size(params);
+                            commands.entity(e).insert(DesiredFamilySize(desired));
+                            desired
+                        }
+                    };
+                    if parity >= desired {
+                        continue;
+                    }
+                }
+
+                // conception_rate applies within marriage; cohabiting couples are scaled by cohabitation_fertility_ratio
+                let rate = if married_opt.is_some() {
+                    params.conception_rate
+                } else {
+                    params.conception_rate * params.cohabitation_fertility_ratio
+                } * parity_fertility_ratio(parity, params)
+                    * density.as_deref().map_or(1.0, |d| d.fertility_factor(location_opt, params))
+                    * modifier_opt.map_or(1.0, |m| m.conception);
+                let conception_prob = 1.0 - (-CONCEPTION_TIMESTEP * rate).exp(); // f32.exp() is e^(f32)
                 if random::<f32>() < conception_prob {
                     debug!("{:?} conceived at age {} with partner {:?}!", e, demog.age, partner.0);
-                    commands.entity(e).insert(RemainingGestation(params.gestation_duration));
+                    let duration = sample_gestation_duration(params);
+                    commands.entity(e).insert((
+                        RemainingGestation(duration),
+                        Pregnancy { id: *next_pregnancy_id, father: partner.0, duration },
+                    ));
+                    *next_pregnancy_id += 1;
                 }
             }
         }
     }
+}
+
+pub fn log_conceptions(
+    query: Query<(Entity, &Demog, &Pregnancy), Added<Pregnancy>>,
+    mut conception_events: EventWriter<ConceptionEvent>,
+    time: Res<Time>
+) {
+    for (e, demog, pregnancy) in query.iter() {
+        conception_events.send(ConceptionEvent {
+            pregnancy_id: pregnancy.id,
+            mother_entity: e,
+            father_entity: pregnancy.father,
+            age: demog.age,
+            gestation_duration: pregnancy.duration,
+            time: time.elapsed_seconds(),
+        });
+    }
 }
\ No newline at end of file
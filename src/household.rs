@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use rand::prelude::random;
+
+use crate::individual::{Individual, Demog, Elder, Sex};
+use crate::partner::{Partner, PartnerEvent, BreakupEvent, UnionType};
+use crate::gestation::{Mother, Father};
+use crate::location::{Location, HOME_SPREAD, MOVE_OUT_DISTANCE};
+use crate::config::{SimulationParameters, CustodyRule};
+use crate::patch::{InPatch, LocalParameters};
+
+use serde::Serialize;
+
+// Records which parent keeps a dependent child when their parents' union dissolves
+#[derive(Event, Serialize, Clone)]
+pub struct CustodyEvent {
+    pub child_entity: Entity,
+    pub custodian: Entity,
+    pub relationship_entity: Entity,
+    pub time: f32,
+}
+
+pub struct HouseholdPlugin;
+
+impl Plugin for HouseholdPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<CustodyEvent>()
+
+        //-- HOUSEHOLDS
+        .add_systems(Update, (
+            join_household_at_birth,
+            form_partner_households,
+            split_households_on_breakup,
+            (
+                leave_parental_home,
+                elders_join_children,
+                dissolve_empty_households,
+            ).run_if(on_timer(Duration::from_secs_f32(HOUSEHOLD_TIMESTEP))),
+        ));
+    }
+}
+
+//-- HOUSEHOLDS
+const HOUSEHOLD_TIMESTEP: f32 = 1.0/12.0;
+
+// ------ HOUSEHOLDS ------
+
+#[derive(Component)]
+pub struct Household;
+
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct HouseholdMember(pub Entity);
+
+// Set once an individual has moved out of the household they were born into
+#[derive(Component)]
+pub struct LeftHome;
+
+// Individuals just added to the population who don't belong to a household yet
+type Unhoused = (Added<Individual>, Without<HouseholdMember>);
+
+// Children still living in the household they were born into, with their parents
+pub type DependentChildren<'w, 's> = Query<'w, 's, (Entity, Option<&'static Mother>, Option<&'static Father>, &'static HouseholdMember), Without<LeftHome>>;
+
+// Children who have set up their own household, with their parents
+pub type GrownChildren<'w, 's> = Query<'w, 's, (Option<&'static Mother>, Option<&'static Father>, &'static HouseholdMember), With<LeftHome>>;
+
+fn is_parent(e: Entity, mother_opt: Option<&Mother>, father_opt: Option<&Father>) -> bool {
+    mother_opt.is_some_and(|mother| mother.0 == e) || father_opt.is_some_and(|father| father.0 == e)
+}
+
+pub fn new_household(commands: &mut Commands, location: Option<Location>) -> Entity {
+    let household = commands.spawn(Household).id();
+    if let Some(location) = location {
+        commands.entity(household).insert(location);
+    }
+    debug!("New household {:?} at {:?}", household, location);
+    household
+}
+
+fn move_to(commands: &mut Commands, e: Entity, household: Entity, location: Option<Location>) {
+    commands.entity(e).insert(HouseholdMember(household));
+    if let Some(location) = location {
+        commands.entity(e).insert(location);
+    }
+}
+
+// Move an individual to another household, taking along their dependent children who live with them
+pub fn move_with_children(
+    commands: &mut Commands,
+    e: Entity,
+    from: Option<Entity>,
+    to: Entity,
+    location: Option<Location>,
+    children_query: &DependentChildren
+) {
+    move_to(commands, e, to, location);
+    for (child, mother_opt, father_opt, member) in children_query.iter() {
+        if is_parent(e, mother_opt, father_opt) && Some(member.0) == from {
+            debug!("{:?} moves with their parent {:?} to household {:?}", child, e, to);
+            move_to(commands, child, to, location);
+        }
+    }
+}
+
+pub fn join_household_at_birth(
+    mut commands: Commands,
+    query: Query<(Entity, Option<&Mother>, Option<&Location>), Unhoused>,
+    member_query: Query<&HouseholdMember>
+) {
+    for (e, mother_opt, location_opt) in query.iter() {
+        // Newborns join their mother's household, anyone else starts their own
+        let household = match mother_opt.and_then(|mother| member_query.get(mother.0).ok()) {
+            Some(member) => member.0,
+            None => new_household(&mut commands, location_opt.copied()),
+        };
+        commands.entity(e).insert(HouseholdMember(household));
+    }
+}
+
+pub fn form_partner_households(
+    mut commands: Commands,
+    mut partner_events: EventReader<PartnerEvent>,
+    member_query: Query<&HouseholdMember>,
+    children_query: DependentChildren
+) {
+    for event in partner_events.read() {
+        if event.union_type == UnionType::Casual {
+            continue;  // casual partners don't move in together
+        }
+
+        let location = event.location.map(|coords| Location(Vec2::from_array(coords)));
+        let household = new_household(&mut commands, location);
+        for e in [event.individual1, event.individual2] {
+            let from = member_query.get(e).ok().map(|m| m.0);
+            move_with_children(&mut commands, e, from, household, location, &children_query);
+            commands.entity(e).insert(LeftHome);
+        }
+        debug!("{:?} and {:?} moved in together in household {:?}", event.individual1, event.individual2, household);
+    }
+}
+
+pub fn split_households_on_breakup(
+    mut commands: Commands,
+    mut breakup_events: EventReader<BreakupEvent>,
+    mut custody_events: EventWriter<CustodyEvent>,
+    person_query: Query<(&Demog, Option<&HouseholdMember>, Option<&Location>)>,
+    children_query: DependentChildren,
+    params: Res<SimulationParameters>,
+    time: Res<Time>
+) {
+    for event in breakup_events.read() {
+        if event.union_type == UnionType::Casual {
+            continue;
+        }
+
+        // In opposite-sex couples the man moves out, otherwise the second partner does
+        let (leaver, stayer) = match person_query.get(event.partner1) {
+            Ok((demog, _, _)) if demog.sex == Sex::Male => (event.partner1, event.partner2),
+            _ => (event.partner2, event.partner1),
+        };
+
+        let Ok((_, Some(member), location_opt)) = person_query.get(leaver) else {
+            continue;
+        };
+        let from = member.0;
+        let location = location_opt.map(|l| l.away(MOVE_OUT_DISTANCE * params.grid_width, &params));
+        let household = new_household(&mut commands, location);
+        move_to(&mut commands, leaver, household, location);
+        debug!("{:?} moved out to household {:?} after breakup", leaver, household);
+
+        // Dependent children of the couple go to one parent according to the custody rule
+        for (child, mother_opt, father_opt, member) in children_query.iter() {
+            if member.0 != from {
+                continue;
+            }
+            let mother = mother_opt.map(|m| m.0).filter(|m| *m == leaver || *m == stayer);
+            let father = father_opt.map(|f| f.0).filter(|f| *f == leaver || *f == stayer);
+            let custodian = match (mother, father) {
+                (Some(mother), Some(father)) => match params.custody_rule {
+                    CustodyRule::Mother => mother,
+                    CustodyRule::Father => father,
+                    CustodyRule::Random => if random::<bool>() { mother } else { father },
+                },
+                (Some(parent), None) | (None, Some(parent)) => parent,
+                (None, None) => continue,  // not a child of this couple
+            };
+
+            if custodian == leaver {
+                move_to(&mut commands, child, household, location);
+            }
+            debug!("Custody of {:?} assigned to {:?}", child, custodian);
+            custody_events.send(CustodyEvent {
+                child_entity: child,
+                custodian,
+                relationship_entity: event.relationship_entity,
+                time: time.elapsed_seconds(),
+            });
+        }
+    }
+}
+
+// A child who may be old enough to leave, and whether they have a partner to leave with
+type HomeLeaver = (Entity, &'static Demog, Option<&'static Partner>, Option<&'static Location>, Option<&'static InPatch>);
+
+pub fn leave_parental_home(
+    mut commands: Commands,
+    query: Query<HomeLeaver, (With<Mother>, Without<LeftHome>)>,
+    params: LocalParameters
+) {
+    for (e, demog, partner_opt, location_opt, patch_opt) in query.iter() {
+        let params = params.get(patch_opt);
+        if demog.age >= params.leave_home_age {
+            if partner_opt.is_none() {
+                let location = location_opt.map(|l| l.near(HOME_SPREAD, params));
+                let household = new_household(&mut commands, location);
+                move_to(&mut commands, e, household, location);
+                debug!("{:?} left home at age {:.1} for household {:?}", e, demog.age, household);
+            }
+            commands.entity(e).insert(LeftHome);
+        }
+    }
+}
+
+pub fn elders_join_children(
+    mut commands: Commands,
+    elder_query: Query<(Entity, &HouseholdMember, Option<&InPatch>), With<Elder>>,
+    member_query: Query<&HouseholdMember>,
+    children_query: GrownChildren,
+    household_query: Query<&Location, With<Household>>,
+    params: LocalParameters
+) {
+    let mut household_sizes: HashMap<Entity, usize> = HashMap::new();
+    for member in member_query.iter() {
+        *household_sizes.entry(member.0).or_default() += 1;
+    }
+
+    // Elders living alone move in with one of their adult children
+    for (e, member, patch_opt) in elder_query.iter() {
+        let join_prob = 1.0 - (-HOUSEHOLD_TIMESTEP * params.get(patch_opt).elder_coresidence_rate).exp();
+        if household_sizes.get(&member.0) == Some(&1) && random::<f32>() < join_prob {
+            let child_household = children_query.iter()
+                .find(|(mother_opt, father_opt, _)| is_parent(e, *mother_opt, *father_opt))
+                .map(|(_, _, child_member)| child_member.0);
+            if let Some(household) = child_household {
+                debug!("Elder {:?} moved in with their child in household {:?}", e, household);
+                move_to(&mut commands, e, household, household_query.get(household).ok().copied());
+            }
+        }
+    }
+}
+
+pub fn dissolve_empty_households(
+    mut commands: Commands,
+    household_query: Query<Entity, With<Household>>,
+    member_query: Query<&HouseholdMember>
+) {
+    let mut household_sizes: HashMap<Entity, usize> = HashMap::new();
+    for member in member_query.iter() {
+        *household_sizes.entry(member.0).or_default() += 1;
+    }
+
+    for household in household_query.iter() {
+        if !household_sizes.contains_key(&household) {
+            debug!("Household {:?} dissolved", household);
+            commands.entity(household).despawn();
+        }
+    }
+}
@@ -7,7 +7,12 @@ use rand::{
     Rng,
 };
 
-use crate::gestation::Mother;
+use crate::gestation::{Mother, Father};
+use crate::partner::detect_widows;
+use crate::location::Location;
+use crate::patch::{InPatch, Patches, LocalParameters};
+use crate::density::PopulationDensity;
+use crate::intervention::HazardModifier;
 use crate::config::{SimulationParameters, Args};
 
 use serde::Serialize;
@@ -16,16 +21,71 @@ use serde::Serialize;
 pub struct BirthEvent {
     pub child_entity: Entity,
     pub mother_entity: Option<Entity>,
+    pub father_entity: Option<Entity>,
+    pub pregnancy_id: Option<u32>,  // shared by the children of a multiple birth
+    pub sex: Sex,
+    pub maternal_transfer: MaternalTransfer,
+    pub location: [f32; 2],
     pub time: f32,
 }
 
+// Maternal attributes a newborn received at birth, by MaternalTransferRule::attribute
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+#[serde(transparent)]
+pub struct MaternalTransfer(pub Vec<String>);
+
+impl MaternalTransfer {
+    pub fn received(&self, attribute: &str) -> bool {
+        self.0.iter().any(|a| a == attribute)
+    }
+}
+
+// How an individual came to be spawned; the default is a founder or immigrant without parents
+#[derive(Debug, Clone, Default)]
+pub struct BirthOrigin {
+    pub mother: Option<Entity>,
+    pub father: Option<Entity>,
+    pub pregnancy_id: Option<u32>,
+    pub maternal_transfer: MaternalTransfer,
+}
+
 #[derive(Event, Serialize, Clone)]
 pub struct DeathEvent {
     pub entity: Entity,
     pub age: f32,
+    pub cause: DeathCause,
+    pub location: Option<[f32; 2]>,
     pub time: f32,
 }
 
+// Set by whichever system marks the individual as Dying
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize)]
+pub enum DeathCause {
+    OldAge,  // reached death_age
+    Background,
+    Maternal,  // at or around delivery
+    Infant,  // excess mortality in the first year of life
+    Disease,
+    Custom(&'static str),  // for systems added by library users
+}
+
+impl DeathCause {
+    pub fn name(&self) -> &str {
+        match self {
+            DeathCause::OldAge => "old_age",
+            DeathCause::Background => "background",
+            DeathCause::Maternal => "maternal",
+            DeathCause::Infant => "infant",
+            DeathCause::Disease => "disease",
+            DeathCause::Custom(name) => name,
+        }
+    }
+}
+
+// Marks an individual who died this step; remove_dead logs the death and despawns them in PostUpdate
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Dying(pub DeathCause);
+
 pub struct IndividualPlugin;
 
 impl Plugin for IndividualPlugin {
@@ -39,7 +99,9 @@ impl Plugin for IndividualPlugin {
         .add_systems(Update, (
             spawn_births,
             update_age.run_if(on_timer(Duration::from_secs_f32(AGING_TIMESTEP))),
-        ));
+        ))
+        // After Update so no system still has commands queued for the deceased
+        .add_systems(PostUpdate, remove_dead.before(detect_widows));
     }
 }
 
@@ -47,7 +109,7 @@ impl Plugin for IndividualPlugin {
 const AGING_TIMESTEP: f32 = 1.0/12.0;
 // DEATH_AGE and PARTNER_SEEKING_AGE now come from SimulationParameters
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Serialize)]
 pub enum Sex {
     Female,
     Male,
@@ -57,6 +119,18 @@ impl Default for Sex {
     fn default() -> Self { Sex::Female }
 }
 
+impl Sex {
+    // Draw a sex given the number of males per 100 females
+    pub fn draw(sex_ratio: f32) -> Sex {
+        if rand::random::<f32>() < sex_ratio / (100.0 + sex_ratio) {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+}
+
+// Even odds, for library users; the simulation draws with Sex::draw
 impl Distribution<Sex> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Sex {
         match rng.gen_range(0..2) {
@@ -86,6 +160,8 @@ pub struct Demog {
 pub fn initial_population(
     mut commands: Commands,
     args: Res<Args>,
+    params: Res<SimulationParameters>,
+    patches: Option<Res<Patches>>,
     mut birth_events: EventWriter<BirthEvent>,
     time: Res<Time>
 ) {
@@ -95,7 +171,9 @@ pub fn initial_population(
     for _ in 0..args.initial_population {
         // Spawn individuals with random ages between 18-30 for variety
         let age = 18.0 + rand::random::<f32>() * 12.0; // 18-30 years old
-        spawn_individual(&mut commands, age, None, &mut birth_events, &time);
+        let sex = Sex::draw(params.initial_sex_ratio);
+        let location = patches.as_deref().map_or_else(|| Location::random(&params), |patches| patches.place_founder(&params));
+        spawn_individual(&mut commands, age, sex, location, BirthOrigin::default(), &mut birth_events, &time);
     }
 
     if args.initial_population > 0 {
@@ -106,29 +184,38 @@ pub fn initial_population(
 pub fn spawn_individual(
     commands: &mut Commands,
     age: f32,
-    mother_opt: Option<Entity>,
+    sex: Sex,
+    location: Location,
+    origin: BirthOrigin,
     birth_events: &mut EventWriter<BirthEvent>,
     time: &Res<Time>
 ) -> Entity {
 
-    let sex: Sex = rand::random();
 
     debug!("Adding {}-year-old {:?} in entity (pending)", age, sex);
     let individual_id = commands
         .spawn((Individual, Demog{
             age: age,
             sex: sex,
-        }))
+        }, location))
         .id();
 
-    if let Some(mother) = mother_opt  {
+    if let Some(mother) = origin.mother  {
         commands.entity(individual_id).insert(Mother(mother));
     }
+    if let Some(father) = origin.father  {
+        commands.entity(individual_id).insert(Father(father));
+    }
 
     // Emit birth event
     birth_events.send(BirthEvent {
         child_entity: individual_id,
-        mother_entity: mother_opt,
+        mother_entity: origin.mother,
+        father_entity: origin.father,
+        pregnancy_id: origin.pregnancy_id,
+        sex,
+        maternal_transfer: origin.maternal_transfer,
+        location: location.coords(),
         time: time.elapsed_seconds(),
     });
 
@@ -140,14 +227,17 @@ pub fn spawn_births() {
     // TODO: placeholder for birth-rate dependent spawning of new individuals w/o parents
 }
 
+// An individual growing older, with what sets their life stage and risk of dying
+type Ageing = (Entity, &'static mut Demog, Option<&'static Adult>, Option<&'static Elder>, Option<&'static Location>, Option<&'static InPatch>, Option<&'static HazardModifier>);
+
 pub fn update_age(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Demog, Option<&Adult>, Option<&Elder>)>,
-    params: Res<SimulationParameters>,
-    mut death_events: EventWriter<DeathEvent>,
-    time: Res<Time>
+    mut query: Query<Ageing>,
+    params: LocalParameters,
+    density: Option<Res<PopulationDensity>>
 ) {
-    for (e, mut demog, adult_opt, elder_opt) in query.iter_mut() {
+    for (e, mut demog, adult_opt, elder_opt, location_opt, patch_opt, modifier_opt) in query.iter_mut() {
+        let params = params.get(patch_opt);
 
         demog.age += AGING_TIMESTEP;
 
@@ -161,17 +251,53 @@ pub fn update_age(
             commands.entity(e).insert(Elder);
         }
 
-        if demog.age > params.death_age {
-            debug!("{:?} died", e);
-
-            death_events.send(DeathEvent {
-                entity: e,
-                age: demog.age,
-                time: time.elapsed_seconds(),
-            });
-
-            commands.entity(e).despawn();
+        // Background mortality before death_age, scaled by local crowding under density dependence and by interventions
+        let mortality_rate = params.background_mortality_rate
+            * density.as_deref().map_or(1.0, |d| d.mortality_factor(location_opt, params))
+            * modifier_opt.map_or(1.0, |m| m.mortality);
+        let death_prob = 1.0 - (-AGING_TIMESTEP * mortality_rate).exp();
+
+        // Excess mortality in the first year, with the first month drawn as a single neonatal risk
+        let infant_death_prob = if demog.age <= AGING_TIMESTEP {
+            params.neonatal_mortality_prob
+        } else if demog.age <= 1.0 {
+            1.0 - (-AGING_TIMESTEP * params.infant_mortality_rate).exp()
+        } else {
+            0.0
+        };
+
+        let cause = if demog.age > params.death_age {
+            Some(DeathCause::OldAge)
+        } else if infant_death_prob > 0.0 && rand::random::<f32>() < infant_death_prob {
+            Some(DeathCause::Infant)
+        } else if mortality_rate > 0.0 && rand::random::<f32>() < death_prob {
+            Some(DeathCause::Background)
+        } else {
+            None
+        };
+
+        if let Some(cause) = cause {
+            commands.entity(e).insert(Dying(cause));
         }
     }
 }
 
+// Every death goes through here once, whichever system marked the individual and however many did
+pub fn remove_dead(
+    mut commands: Commands,
+    query: Query<(Entity, &Demog, &Dying, Option<&Location>)>,
+    mut death_events: EventWriter<DeathEvent>,
+    time: Res<Time>
+) {
+    for (e, demog, dying, location_opt) in query.iter() {
+        debug!("{:?} died ({:?})", e, dying.0);
+        death_events.send(DeathEvent {
+            entity: e,
+            age: demog.age,
+            cause: dying.0,
+            location: location_opt.map(|l| l.coords()),
+            time: time.elapsed_seconds(),
+        });
+        commands.entity(e).despawn();
+    }
+}
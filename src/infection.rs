@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::ecs::system::EntityCommands;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use rand::prelude::{random, thread_rng, SliceRandom};
+
+use serde::Serialize;
+
+use crate::individual::{Individual, Demog, BirthEvent, DeathCause, Dying};
+use crate::partner::{Couple, Relationship, ContactEvent};
+use crate::gestation::{Mother, Delivery, MaternalTransferRule, AddMaternalTransfer};
+use crate::household::HouseholdMember;
+use crate::patch::{InPatch, LocalParameters};
+use crate::config::SimulationParameters;
+
+#[derive(Event, Serialize, Clone)]
+pub struct InfectionEvent {
+    pub entity: Entity,
+    pub source: Option<Entity>,  // None for the initial seeding
+    pub route: TransmissionRoute,
+    pub age: f32,
+    pub time: f32,
+}
+
+pub struct InfectionPlugin;
+
+impl Plugin for InfectionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<InfectionEvent>()
+            .add_maternal_transfer(VerticalTransmission)
+            .add_maternal_transfer(MaternalAntibodyTransfer)
+
+        //-- INFECTION
+        .add_systems(Update, (
+            seed_infection,
+            log_vertical_transmission,
+            contact_transmission,
+            (
+                partner_transmission,
+                household_transmission,
+                progress_infection,
+                wane_maternal_antibodies,
+            ).run_if(on_timer(Duration::from_secs_f32(INFECTION_TIMESTEP))),
+        ).chain());
+    }
+}
+
+//-- INFECTION
+const INFECTION_TIMESTEP: f32 = 1.0/52.0;
+
+// Attributes recorded in BirthEvent::maternal_transfer; newborns without either are left to seed_infection to start susceptible
+pub const VERTICAL_INFECTION: &str = "infection";
+pub const MATERNAL_ANTIBODIES: &str = "antibodies";
+
+// ------ INFECTION ------
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Default, Serialize)]
+pub enum DiseaseState {
+    #[default]
+    Susceptible,
+    Infected,
+    Recovered,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub enum TransmissionRoute {
+    Seed,
+    Partner,    // per time step along a relationship
+    Contact,    // per sexual act
+    Household,
+    Vertical,   // mother to child at birth
+}
+
+// Protection passed on by an immune mother; the child is Recovered until it wanes
+#[derive(Component)]
+pub struct MaternalAntibodies;
+
+fn infect(
+    state: &mut DiseaseState,
+    e: Entity,
+    source: Option<Entity>,
+    route: TransmissionRoute,
+    age: f32,
+    infection_events: &mut EventWriter<InfectionEvent>,
+    time: &Res<Time>
+) {
+    debug!("{:?} infected by {:?} via {:?}", e, source, route);
+    *state = DiseaseState::Infected;
+    infection_events.send(InfectionEvent {
+        entity: e,
+        source,
+        route,
+        age,
+        time: time.elapsed_seconds(),
+    });
+}
+
+// Individuals just added to the population who have no disease state yet
+type Unseeded = (Added<Individual>, Without<DiseaseState>);
+
+// Founders are infected with probability initial_prevalence, everyone else starts susceptible
+pub fn seed_infection(
+    mut commands: Commands,
+    query: Query<(Entity, &Demog, Option<&Mother>), Unseeded>,
+    params: Res<SimulationParameters>,
+    mut infection_events: EventWriter<InfectionEvent>,
+    time: Res<Time>
+) {
+    for (e, demog, mother_opt) in query.iter() {
+        let mut state = DiseaseState::Susceptible;
+        if mother_opt.is_none() && random::<f32>() < params.initial_prevalence {
+            infect(&mut state, e, None, TransmissionRoute::Seed, demog.age, &mut infection_events, &time);
+        }
+        commands.entity(e).insert(state);
+    }
+}
+
+// Infected mothers pass on the infection with vertical_transmission_prob
+pub struct VerticalTransmission;
+
+impl MaternalTransferRule for VerticalTransmission {
+    fn attribute(&self) -> &str {
+        VERTICAL_INFECTION
+    }
+
+    fn probability(&self, delivery: &Delivery) -> f32 {
+        match delivery.disease {
+            Some(DiseaseState::Infected) => delivery.params.vertical_transmission_prob,
+            _ => 0.0,
+        }
+    }
+
+    fn apply(&self, child: &mut EntityCommands) {
+        child.insert(DiseaseState::Infected);
+    }
+}
+
+// Immune mothers pass on antibodies with maternal_antibody_prob, protecting the child until they wane
+pub struct MaternalAntibodyTransfer;
+
+impl MaternalTransferRule for MaternalAntibodyTransfer {
+    fn attribute(&self) -> &str {
+        MATERNAL_ANTIBODIES
+    }
+
+    fn probability(&self, delivery: &Delivery) -> f32 {
+        match delivery.disease {
+            Some(DiseaseState::Recovered) => delivery.params.maternal_antibody_prob,
+            _ => 0.0,
+        }
+    }
+
+    fn apply(&self, child: &mut EntityCommands) {
+        child.insert((DiseaseState::Recovered, MaternalAntibodies));
+    }
+}
+
+pub fn log_vertical_transmission(
+    mut birth_events: EventReader<BirthEvent>,
+    mut infection_events: EventWriter<InfectionEvent>
+) {
+    for event in birth_events.read() {
+        if event.maternal_transfer.received(VERTICAL_INFECTION) {
+            debug!("{:?} infected at birth by {:?}", event.child_entity, event.mother_entity);
+            infection_events.send(InfectionEvent {
+                entity: event.child_entity,
+                source: event.mother_entity,
+                route: TransmissionRoute::Vertical,
+                age: 0.0,
+                time: event.time,
+            });
+        }
+    }
+}
+
+pub fn contact_transmission(
+    mut contact_events: EventReader<ContactEvent>,
+    mut query: Query<(&mut DiseaseState, &Demog, Option<&InPatch>)>,
+    params: LocalParameters,
+    mut infection_events: EventWriter<InfectionEvent>,
+    time: Res<Time>
+) {
+    for event in contact_events.read() {
+        let (Ok((state1, _, _)), Ok((state2, _, _))) = (query.get(event.individual1), query.get(event.individual2)) else {
+            continue;
+        };
+        let (target, source) = match (*state1, *state2) {
+            (DiseaseState::Infected, DiseaseState::Susceptible) => (event.individual2, event.individual1),
+            (DiseaseState::Susceptible, DiseaseState::Infected) => (event.individual1, event.individual2),
+            _ => continue,
+        };
+        let Ok((mut state, demog, patch_opt)) = query.get_mut(target) else {
+            continue;
+        };
+        let params = params.get(patch_opt);
+        if random::<f32>() < params.transmission_per_act {
+            infect(&mut state, target, Some(source), TransmissionRoute::Contact, demog.age, &mut infection_events, &time);
+        }
+    }
+}
+
+pub fn partner_transmission(
+    rel_query: Query<&Couple, With<Relationship>>,
+    mut query: Query<(&mut DiseaseState, &Demog, Option<&InPatch>)>,
+    params: LocalParameters,
+    mut infection_events: EventWriter<InfectionEvent>,
+    time: Res<Time>
+) {
+    // Decide on the states at the start of the step so infections don't chain within it
+    let mut transmissions = Vec::new();
+    for partners in rel_query.iter() {
+        let (Ok((state1, _, _)), Ok((state2, _, _))) = (query.get(partners.e1), query.get(partners.e2)) else {
+            continue;
+        };
+        match (*state1, *state2) {
+            (DiseaseState::Infected, DiseaseState::Susceptible) => transmissions.push((partners.e2, partners.e1)),
+            (DiseaseState::Susceptible, DiseaseState::Infected) => transmissions.push((partners.e1, partners.e2)),
+            _ => {}
+        }
+    }
+
+    for (target, source) in transmissions {
+        let Ok((mut state, demog, patch_opt)) = query.get_mut(target) else {
+            continue;
+        };
+        let params = params.get(patch_opt);
+        let transmission_prob = 1.0 - (-INFECTION_TIMESTEP * params.partner_transmission_rate).exp();
+        if *state == DiseaseState::Susceptible && random::<f32>() < transmission_prob {
+            infect(&mut state, target, Some(source), TransmissionRoute::Partner, demog.age, &mut infection_events, &time);
+        }
+    }
+}
+
+pub fn household_transmission(
+    mut query: Query<(Entity, &HouseholdMember, &mut DiseaseState, &Demog, Option<&InPatch>)>,
+    params: LocalParameters,
+    mut infection_events: EventWriter<InfectionEvent>,
+    time: Res<Time>
+) {
+    let mut infected: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    for (e, member, state, _, _) in query.iter() {
+        if *state == DiseaseState::Infected {
+            infected.entry(member.0).or_default().push(e);
+        }
+    }
+    if infected.is_empty() {
+        return;
+    }
+
+    // Each infected member exerts the same hazard on every susceptible one
+    for (e, member, mut state, demog, patch_opt) in query.iter_mut() {
+        let Some(sources) = infected.get(&member.0) else {
+            continue;
+        };
+        if *state != DiseaseState::Susceptible {
+            continue;
+        }
+        let params = params.get(patch_opt);
+        let rate = params.household_transmission_rate * sources.len() as f32;
+        let transmission_prob = 1.0 - (-INFECTION_TIMESTEP * rate).exp();
+        if random::<f32>() < transmission_prob {
+            let source = sources.choose(&mut thread_rng()).copied();
+            infect(&mut state, e, source, TransmissionRoute::Household, demog.age, &mut infection_events, &time);
+        }
+    }
+}
+
+// Infected individuals die of the disease or recover, to immunity or back to susceptible
+pub fn progress_infection(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DiseaseState, Option<&InPatch>)>,
+    params: LocalParameters
+) {
+    for (e, mut state, patch_opt) in query.iter_mut() {
+        if *state != DiseaseState::Infected {
+            continue;
+        }
+        let params = params.get(patch_opt);
+
+        let death_prob = 1.0 - (-INFECTION_TIMESTEP * params.infection_mortality_rate).exp();
+        if random::<f32>() < death_prob {
+            commands.entity(e).insert(Dying(DeathCause::Disease));
+            continue;
+        }
+
+        let recovery_prob = 1.0 - (-INFECTION_TIMESTEP * params.recovery_rate).exp();
+        if random::<f32>() < recovery_prob {
+            debug!("{:?} recovered", e);
+            *state = if params.infection_immunity {
+                DiseaseState::Recovered
+            } else {
+                DiseaseState::Susceptible
+            };
+        }
+    }
+}
+
+pub fn wane_maternal_antibodies(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DiseaseState, Option<&InPatch>), With<MaternalAntibodies>>,
+    params: LocalParameters
+) {
+    for (e, mut state, patch_opt) in query.iter_mut() {
+        let waning_prob = 1.0 - (-INFECTION_TIMESTEP * params.get(patch_opt).maternal_antibody_waning_rate).exp();
+        if random::<f32>() < waning_prob {
+            debug!("{:?} lost maternal antibodies", e);
+            commands.entity(e).remove::<MaternalAntibodies>();
+            if *state == DiseaseState::Recovered {
+                *state = DiseaseState::Susceptible;
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use rand::prelude::random;
+
+use serde::Serialize;
+
+use crate::individual::{Demog, Sex};
+use crate::partner::{Partner, Married};
+use crate::gestation::Parity;
+
+// Emitted when an individual is enrolled in an intervention
+#[derive(Event, Serialize, Clone)]
+pub struct EnrolmentEvent {
+    pub entity: Entity,
+    pub intervention: String,
+    pub age: f32,
+    pub time: f32,
+}
+
+pub struct InterventionPlugin;
+
+impl Plugin for InterventionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Interventions>()
+            .add_event::<EnrolmentEvent>()
+
+        //-- INTERVENTIONS
+        .add_systems(Update, enrol_interventions
+            .run_if(on_timer(Duration::from_secs_f32(INTERVENTION_TIMESTEP))));
+    }
+}
+
+//-- INTERVENTIONS
+const INTERVENTION_TIMESTEP: f32 = 1.0/12.0;
+
+// ------ INTERVENTIONS ------
+
+// Multipliers on an individual's hazards, 1.0 leaves a hazard unchanged
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct HazardModifier {
+    pub conception: f32,
+    pub mortality: f32,
+    pub breakup: f32,
+}
+
+impl Default for HazardModifier {
+    fn default() -> Self {
+        Self { conception: 1.0, mortality: 1.0, breakup: 1.0 }
+    }
+}
+
+impl HazardModifier {
+    // Effects of several interventions multiply
+    pub fn combine(&self, other: &HazardModifier) -> HazardModifier {
+        HazardModifier {
+            conception: self.conception * other.conception,
+            mortality: self.mortality * other.mortality,
+            breakup: self.breakup * other.breakup,
+        }
+    }
+}
+
+// What an intervention sees of an individual when deciding eligibility
+pub struct Candidate<'a> {
+    pub entity: Entity,
+    pub demog: &'a Demog,
+    pub partner: Option<Entity>,
+    pub married: bool,
+    pub parity: usize,  // live-born children, zero for men
+}
+
+// A policy acting on selected individuals; register with App::add_intervention
+pub trait Intervention: Send + Sync + 'static {
+    fn name(&self) -> &str;
+
+    // Whether the intervention runs at `time` in years; enrolled individuals lose its effect outside this window
+    fn active(&self, time: f32) -> bool;
+
+    // Fraction of eligible individuals who enrol, each decides once when first found eligible
+    fn coverage(&self) -> f32 {
+        1.0
+    }
+
+    fn eligible(&self, candidate: &Candidate) -> bool;
+
+    fn modifier(&self) -> HazardModifier;
+}
+
+#[derive(Resource, Default)]
+pub struct Interventions(pub Vec<Box<dyn Intervention>>);
+
+pub trait AddIntervention {
+    fn add_intervention(&mut self, intervention: impl Intervention) -> &mut Self;
+}
+
+impl AddIntervention for App {
+    fn add_intervention(&mut self, intervention: impl Intervention) -> &mut Self {
+        self.world.get_resource_or_insert_with(Interventions::default).0.push(Box::new(intervention));
+        self
+    }
+}
+
+// Indices into Interventions of the programs an individual has joined or turned down
+#[derive(Component, Clone, Default, Debug)]
+pub struct Enrolment {
+    pub enrolled: Vec<usize>,
+    pub declined: Vec<usize>,
+}
+
+impl Enrolment {
+    fn decided(&self, intervention: usize) -> bool {
+        self.enrolled.contains(&intervention) || self.declined.contains(&intervention)
+    }
+}
+
+// Lower conception rate for women who already have min_parity children
+pub struct FamilyPlanning {
+    pub start: f32,
+    pub end: f32,
+    pub coverage: f32,
+    pub min_parity: usize,
+    pub conception_factor: f32,
+}
+
+impl Intervention for FamilyPlanning {
+    fn name(&self) -> &str {
+        "family_planning"
+    }
+
+    fn active(&self, time: f32) -> bool {
+        time >= self.start && time < self.end
+    }
+
+    fn coverage(&self) -> f32 {
+        self.coverage
+    }
+
+    fn eligible(&self, candidate: &Candidate) -> bool {
+        candidate.demog.sex == Sex::Female && candidate.parity >= self.min_parity
+    }
+
+    fn modifier(&self) -> HazardModifier {
+        HazardModifier { conception: self.conception_factor, ..default() }
+    }
+}
+
+// An individual with what interventions can target them on, and their current enrolments
+type Enrollee = (Entity, &'static Demog, Option<&'static Partner>, Option<&'static Married>, Option<&'static Parity>, Option<&'static Enrolment>, Option<&'static HazardModifier>);
+
+pub fn enrol_interventions(
+    mut commands: Commands,
+    query: Query<Enrollee>,
+    interventions: Res<Interventions>,
+    mut enrolment_events: EventWriter<EnrolmentEvent>,
+    time: Res<Time>
+) {
+    if interventions.0.is_empty() {
+        return;
+    }
+    let now = time.elapsed_seconds();
+
+    for (e, demog, partner_opt, married_opt, parity_opt, enrolment_opt, modifier_opt) in query.iter() {
+        let candidate = Candidate {
+            entity: e,
+            demog,
+            partner: partner_opt.map(|p| p.0),
+            married: married_opt.is_some(),
+            parity: parity_opt.map_or(0, |p| p.0),
+        };
+
+        // Copied only once a new decision is made, most individuals have nothing to decide
+        let mut updated: Option<Enrolment> = None;
+        for (i, intervention) in interventions.0.iter().enumerate() {
+            let decided = updated.as_ref().or(enrolment_opt).is_some_and(|enrolment| enrolment.decided(i));
+            if !intervention.active(now) || decided || !intervention.eligible(&candidate) {
+                continue;
+            }
+            let enrolment = updated.get_or_insert_with(|| enrolment_opt.cloned().unwrap_or_default());
+            if random::<f32>() < intervention.coverage() {
+                debug!("{:?} enrolled in {} at age {:.1}", e, intervention.name(), demog.age);
+                enrolment.enrolled.push(i);
+                enrolment_events.send(EnrolmentEvent {
+                    entity: e,
+                    intervention: intervention.name().to_string(),
+                    age: demog.age,
+                    time: now,
+                });
+            } else {
+                enrolment.declined.push(i);
+            }
+        }
+
+        // Only interventions still running act on their enrollees
+        let enrolled = updated.as_ref().or(enrolment_opt).map_or(&[][..], |enrolment| &enrolment.enrolled);
+        let modifier = enrolled.iter()
+            .map(|i| &interventions.0[*i])
+            .filter(|intervention| intervention.active(now))
+            .fold(HazardModifier::default(), |modifier, intervention| modifier.combine(&intervention.modifier()));
+        if modifier == HazardModifier::default() {
+            if modifier_opt.is_some() {
+                commands.entity(e).remove::<HazardModifier>();
+            }
+        } else if modifier_opt != Some(&modifier) {
+            commands.entity(e).insert(modifier);
+        }
+
+        if let Some(enrolment) = updated {
+            commands.entity(e).insert(enrolment);
+        }
+    }
+}
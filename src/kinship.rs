@@ -0,0 +1,224 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bevy::prelude::*;
+
+use crate::individual::{BirthEvent, DeathEvent};
+use crate::emigration::EmigrationEvent;
+
+pub struct KinshipPlugin;
+
+impl Plugin for KinshipPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Genealogy>()
+
+        //-- GENEALOGY
+        .add_systems(Update, (
+            record_births,
+            record_deaths,
+            record_emigrations,
+        ).chain());
+    }
+}
+
+// ------ GENEALOGY ------
+
+#[derive(Clone, Debug)]
+pub struct KinRecord {
+    pub mother: Option<Entity>,
+    pub father: Option<Entity>,
+    pub children: Vec<Entity>,
+    pub entry_time: f32,  // birth, or spawn time for the initial population
+    pub death_time: Option<f32>,
+    pub exit_time: Option<f32>,  // emigration, leaving the population alive
+}
+
+// Every individual ever born, kept after their entity is despawned so kin links never dangle
+#[derive(Resource, Default, Clone)]
+pub struct Genealogy {
+    records: HashMap<Entity, KinRecord>,
+}
+
+impl Genealogy {
+    pub fn record(&self, e: Entity) -> Option<&KinRecord> {
+        self.records.get(&e)
+    }
+
+    pub fn records(&self) -> impl Iterator<Item = (&Entity, &KinRecord)> {
+        self.records.iter()
+    }
+
+    pub fn add_birth(&mut self, child: Entity, mother: Option<Entity>, father: Option<Entity>, time: f32) {
+        for parent in [mother, father].into_iter().flatten() {
+            if let Some(record) = self.records.get_mut(&parent) {
+                record.children.push(child);
+            }
+        }
+        self.records.insert(child, KinRecord {
+            mother,
+            father,
+            children: Vec::new(),
+            entry_time: time,
+            death_time: None,
+            exit_time: None,
+        });
+    }
+
+    pub fn add_death(&mut self, e: Entity, time: f32) {
+        if let Some(record) = self.records.get_mut(&e) {
+            record.death_time = Some(time);
+        }
+    }
+
+    pub fn add_exit(&mut self, e: Entity, time: f32) {
+        if let Some(record) = self.records.get_mut(&e) {
+            record.exit_time = Some(time);
+        }
+    }
+
+    // Alive and still in the population; emigrants are gone from their exit on
+    pub fn is_alive(&self, e: Entity, time: f32) -> bool {
+        self.records.get(&e)
+            .is_some_and(|r| r.entry_time <= time && !r.death_time.or(r.exit_time).is_some_and(|end| end <= time))
+    }
+
+    pub fn mother(&self, e: Entity) -> Option<Entity> {
+        self.records.get(&e).and_then(|r| r.mother)
+    }
+
+    pub fn father(&self, e: Entity) -> Option<Entity> {
+        self.records.get(&e).and_then(|r| r.father)
+    }
+
+    pub fn parents(&self, e: Entity) -> Vec<Entity> {
+        self.records.get(&e)
+            .map(|r| [r.mother, r.father].into_iter().flatten().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn children(&self, e: Entity) -> Vec<Entity> {
+        self.records.get(&e).map(|r| r.children.clone()).unwrap_or_default()
+    }
+
+    // Everyone sharing at least one known parent, excluding e itself
+    fn all_siblings(&self, e: Entity) -> Vec<Entity> {
+        let mut siblings: Vec<Entity> = self.parents(e).into_iter()
+            .flat_map(|parent| self.children(parent))
+            .filter(|s| *s != e)
+            .collect();
+        siblings.sort();
+        siblings.dedup();
+        siblings
+    }
+
+    // Full siblings: same mother and same father
+    pub fn siblings(&self, e: Entity) -> Vec<Entity> {
+        self.all_siblings(e).into_iter()
+            .filter(|s| self.mother(*s) == self.mother(e) && self.father(*s) == self.father(e))
+            .collect()
+    }
+
+    // Half siblings: exactly one parent in common
+    pub fn half_siblings(&self, e: Entity) -> Vec<Entity> {
+        self.all_siblings(e).into_iter()
+            .filter(|s| self.mother(*s) != self.mother(e) || self.father(*s) != self.father(e))
+            .collect()
+    }
+
+    pub fn grandparents(&self, e: Entity) -> Vec<Entity> {
+        self.parents(e).into_iter()
+            .flat_map(|parent| self.parents(parent))
+            .collect()
+    }
+
+    pub fn grandchildren(&self, e: Entity) -> Vec<Entity> {
+        self.children(e).into_iter()
+            .flat_map(|child| self.children(child))
+            .collect()
+    }
+
+    // First cousins: children of the parents' full and half siblings
+    pub fn cousins(&self, e: Entity) -> Vec<Entity> {
+        let mut cousins: Vec<Entity> = self.parents(e).into_iter()
+            .flat_map(|parent| self.all_siblings(parent))
+            .flat_map(|aunt_or_uncle| self.children(aunt_or_uncle))
+            .collect();
+        cousins.sort();
+        cousins.dedup();
+        cousins
+    }
+
+    // Consanguineous kin up to max_degree, keyed by degree of relationship
+    // (parents and children 1, siblings and grandparents 2, aunts, uncles and nieces 3, first cousins 4)
+    pub fn kin_by_degree(&self, e: Entity, max_degree: u32) -> HashMap<Entity, u32> {
+        let mut degrees: HashMap<Entity, u32> = HashMap::new();
+
+        // Walk up to each ancestor, then down through their descendants
+        let mut ancestors: Vec<(Entity, u32)> = vec![(e, 0)];
+        let mut frontier: Vec<(Entity, u32)> = vec![(e, 0)];
+        while let Some((current, up)) = frontier.pop() {
+            if up < max_degree {
+                for parent in self.parents(current) {
+                    ancestors.push((parent, up + 1));
+                    frontier.push((parent, up + 1));
+                }
+            }
+        }
+
+        for (ancestor, up) in ancestors {
+            let mut visited: HashSet<Entity> = HashSet::new();
+            let mut frontier: Vec<(Entity, u32)> = vec![(ancestor, up)];
+            while let Some((current, degree)) = frontier.pop() {
+                if !visited.insert(current) {
+                    continue;
+                }
+                let best = degrees.entry(current).or_insert(degree);
+                *best = (*best).min(degree);
+                if degree < max_degree {
+                    frontier.extend(self.children(current).into_iter().map(|child| (child, degree + 1)));
+                }
+            }
+        }
+
+        degrees.remove(&e);
+        degrees
+    }
+
+    // Number of kin alive in the population at the given time by degree of relationship
+    pub fn living_kin_by_degree(&self, e: Entity, max_degree: u32, time: f32) -> BTreeMap<u32, usize> {
+        let mut counts: BTreeMap<u32, usize> = (1..=max_degree).map(|degree| (degree, 0)).collect();
+        for (kin, degree) in self.kin_by_degree(e, max_degree) {
+            if self.is_alive(kin, time) {
+                *counts.entry(degree).or_default() += 1;
+            }
+        }
+        counts
+    }
+}
+
+pub fn record_births(
+    mut genealogy: ResMut<Genealogy>,
+    mut birth_events: EventReader<BirthEvent>
+) {
+    for event in birth_events.read() {
+        genealogy.add_birth(event.child_entity, event.mother_entity, event.father_entity, event.time);
+    }
+}
+
+pub fn record_deaths(
+    mut genealogy: ResMut<Genealogy>,
+    mut death_events: EventReader<DeathEvent>
+) {
+    for event in death_events.read() {
+        genealogy.add_death(event.entity, event.time);
+    }
+}
+
+pub fn record_emigrations(
+    mut genealogy: ResMut<Genealogy>,
+    mut emigration_events: EventReader<EmigrationEvent>
+) {
+    for event in emigration_events.read() {
+        genealogy.add_exit(event.entity, event.time);
+    }
+}
@@ -3,6 +3,19 @@ pub mod partner;
 pub mod gestation;
 pub mod config;
 pub mod events;
+pub mod household;
+pub mod census;
+pub mod orphanhood;
+pub mod kinship;
+pub mod network;
+pub mod location;
+pub mod patch;
+pub mod emigration;
+pub mod density;
+pub mod scenario;
+pub mod intervention;
+pub mod infection;
+pub mod vaccination;
 // pub mod window;
 
 #[cfg(feature = "python")]
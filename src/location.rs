@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use rand::prelude::random;
+
+use crate::config::SimulationParameters;
+
+//-- SPACE
+pub const NEWBORN_SPREAD: f32 = 1.0;  // newborns are placed within this distance of their mother
+pub const HOME_SPREAD: f32 = 5.0;  // new homes are placed around the partners' midpoint
+pub const MOVE_OUT_DISTANCE: f32 = 0.05;  // fraction of the grid width moved after a breakup
+
+// ------ LOCATION ------
+
+// Position on the landscape in grid units, within [0, grid_width] x [0, grid_height]
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct Location(pub Vec2);
+
+impl Location {
+    pub fn random(params: &SimulationParameters) -> Self {
+        Self(Vec2::new(
+            random::<f32>() * params.grid_width,
+            random::<f32>() * params.grid_height,
+        ))
+    }
+
+    pub fn clamped(v: Vec2, params: &SimulationParameters) -> Self {
+        Self(Vec2::new(
+            v.x.clamp(0.0, params.grid_width),
+            v.y.clamp(0.0, params.grid_height),
+        ))
+    }
+
+    // Uniformly within a square of side `spread` centred here
+    pub fn near(&self, spread: f32, params: &SimulationParameters) -> Self {
+        Self::clamped(self.0 + spread * Vec2::new(random::<f32>() - 0.5, random::<f32>() - 0.5), params)
+    }
+
+    // Uniformly within a disc of `radius` centred here
+    pub fn within(&self, radius: f32, params: &SimulationParameters) -> Self {
+        self.away(radius * random::<f32>().sqrt(), params)
+    }
+
+    // At `distance` in a random direction
+    pub fn away(&self, distance: f32, params: &SimulationParameters) -> Self {
+        let direction = Vec2::from_angle(random::<f32>() * std::f32::consts::TAU);
+        Self::clamped(self.0 + direction * distance, params)
+    }
+
+    pub fn midpoint(&self, other: &Self) -> Self {
+        Self((self.0 + other.0) * 0.5)
+    }
+
+    pub fn distance(&self, other: &Self) -> f32 {
+        self.0.distance(other.0)
+    }
+
+    // Plain coordinates for events and exports
+    pub fn coords(&self) -> [f32; 2] {
+        self.0.to_array()
+    }
+}
+
+// ------ SPATIAL INDEX ------
+
+// Uniform grid of buckets so radius queries only look at nearby cells
+pub struct SpatialIndex<T> {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<(T, Vec2)>>,
+}
+
+impl<T: Copy> SpatialIndex<T> {
+    // Cells the size of the typical query radius keep each query to a 3x3 block
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell(&self, p: Vec2) -> (i32, i32) {
+        ((p.x / self.cell_size).floor() as i32, (p.y / self.cell_size).floor() as i32)
+    }
+
+    pub fn insert(&mut self, item: T, location: &Location) {
+        let cell = self.cell(location.0);
+        self.cells.entry(cell).or_default().push((item, location.0));
+    }
+
+    pub fn within(&self, location: &Location, radius: f32) -> Vec<T> {
+        let (cx, cy) = self.cell(location.0);
+        let reach = (radius / self.cell_size).ceil() as i32;
+        let mut found = Vec::new();
+        for x in cx - reach..=cx + reach {
+            for y in cy - reach..=cy + reach {
+                if let Some(items) = self.cells.get(&(x, y)) {
+                    found.extend(items.iter()
+                        .filter(|(_, p)| p.distance(location.0) <= radius)
+                        .map(|(item, _)| *item));
+                }
+            }
+        }
+        found
+    }
+}
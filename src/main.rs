@@ -1,28 +1,50 @@
-mod individual;
-mod partner;
-mod gestation;
 mod window;
-mod config;
-mod events;
 
-use crate::individual::IndividualPlugin;
-use crate::partner::PartnerPlugin;
-use crate::gestation::GestationPlugin;
+use bevy_abm::individual::IndividualPlugin;
+use bevy_abm::partner::PartnerPlugin;
+use bevy_abm::gestation::GestationPlugin;
+use bevy_abm::config::{ConfigPlugin, Args};
+use bevy_abm::events::EventLogPlugin;
+use bevy_abm::household::HouseholdPlugin;
+use bevy_abm::census::CensusPlugin;
+use bevy_abm::orphanhood::OrphanhoodPlugin;
+use bevy_abm::kinship::KinshipPlugin;
+use bevy_abm::patch::{PatchPlugin, Patches};
+use bevy_abm::emigration::EmigrationPlugin;
+use bevy_abm::density::DensityPlugin;
+use bevy_abm::scenario::{ScenarioPlugin, Scenario};
+use bevy_abm::intervention::InterventionPlugin;
+use bevy_abm::infection::InfectionPlugin;
+use bevy_abm::vaccination::VaccinationPlugin;
 use crate::window::{DisplayPlugin, WINDOW_PIXEL_WIDTH, WINDOW_PIXEL_HEIGHT};
-use crate::config::{ConfigPlugin, Args};
-use crate::events::EventLogPlugin;
 
 use bevy::prelude::*;
 use bevy::window::{Window, WindowPlugin};
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap::error::ErrorKind;
 
 fn main() {
     let args = Args::parse();
     let mut app = App::new();
 
+    if let Some(path) = &args.patches {
+        let patches = Patches::from_file(path).unwrap_or_else(|e| {
+            Args::command().error(ErrorKind::InvalidValue, format!("invalid patches file {}: {}", path, e)).exit()
+        });
+        app.insert_resource(patches);
+    }
+
+    let scenario = match &args.scenario {
+        Some(path) => Scenario::from_file(path).unwrap_or_else(|e| {
+            Args::command().error(ErrorKind::InvalidValue, format!("invalid scenario file {}: {}", path, e)).exit()
+        }),
+        None => Scenario::default(),
+    };
+
     app
         .insert_resource(args)
-        .add_plugins((IndividualPlugin, PartnerPlugin, GestationPlugin, ConfigPlugin, EventLogPlugin));
+        .add_plugins((IndividualPlugin, PartnerPlugin, GestationPlugin, HouseholdPlugin, ConfigPlugin, EventLogPlugin, CensusPlugin, OrphanhoodPlugin, KinshipPlugin))
+        .add_plugins((PatchPlugin, EmigrationPlugin, DensityPlugin, ScenarioPlugin(scenario), InterventionPlugin, InfectionPlugin, VaccinationPlugin));
 
     if cfg!(feature = "headless") {
         app
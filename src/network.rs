@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::Write;
+
+use bevy::prelude::*;
+
+use crate::individual::Sex;
+use crate::partner::UnionType;
+use crate::events::EventLog;
+
+// ------ PEDIGREE AND PARTNERSHIP NETWORK ------
+
+#[derive(Clone, Debug)]
+pub struct NetworkNode {
+    pub entity: Entity,
+    pub sex: Sex,
+    pub birth_time: f32,  // spawn time for the initial population
+    pub death_time: Option<f32>,
+    pub death_age: Option<f32>,
+    pub exit_time: Option<f32>,  // emigration
+    pub founder: bool,  // no recorded parents
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EdgeKind {
+    Mother,   // mother -> child
+    Father,   // father -> child
+    Partner,  // undirected
+}
+
+#[derive(Clone, Debug)]
+pub struct NetworkEdge {
+    pub source: Entity,
+    pub target: Entity,
+    pub kind: EdgeKind,
+    pub union_type: Option<UnionType>,  // latest union type of partner edges
+    pub relationship: Option<Entity>,
+    pub start: f32,
+    pub end: Option<f32>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct PedigreeNetwork {
+    pub nodes: Vec<NetworkNode>,
+    pub edges: Vec<NetworkEdge>,
+}
+
+impl PedigreeNetwork {
+    // Parent-child genealogy and partnership history, built from logged events
+    pub fn from_event_log(event_log: &EventLog) -> Self {
+        let deaths: HashMap<Entity, (f32, f32)> = event_log.deaths.iter()
+            .map(|d| (d.entity, (d.time, d.age)))
+            .collect();
+        let exits: HashMap<Entity, f32> = event_log.emigrations.iter()
+            .map(|e| (e.entity, e.time))
+            .collect();
+
+        let mut network = PedigreeNetwork::default();
+        for birth in event_log.births.iter() {
+            let death = deaths.get(&birth.child_entity);
+            network.nodes.push(NetworkNode {
+                entity: birth.child_entity,
+                sex: birth.sex,
+                birth_time: birth.time,
+                death_time: death.map(|(time, _)| *time),
+                death_age: death.map(|(_, age)| *age),
+                exit_time: exits.get(&birth.child_entity).copied(),
+                founder: birth.mother_entity.is_none() && birth.father_entity.is_none(),
+            });
+
+            for (parent, kind) in [(birth.mother_entity, EdgeKind::Mother), (birth.father_entity, EdgeKind::Father)] {
+                if let Some(parent) = parent {
+                    network.edges.push(NetworkEdge {
+                        source: parent,
+                        target: birth.child_entity,
+                        kind,
+                        union_type: None,
+                        relationship: None,
+                        start: birth.time,
+                        end: None,
+                    });
+                }
+            }
+        }
+
+        // A partnership ends at breakup or widowhood, otherwise when the first partner dies or emigrates
+        let mut ends: HashMap<Entity, f32> = HashMap::new();
+        for (relationship, time) in event_log.breakups.iter().map(|b| (b.relationship_entity, b.time))
+            .chain(event_log.widowings.iter().map(|w| (w.relationship_entity, w.time))) {
+            let end = ends.entry(relationship).or_insert(time);
+            *end = end.min(time);
+        }
+        let married: HashMap<Entity, f32> = event_log.marriages.iter()
+            .map(|m| (m.relationship_entity, m.time))
+            .collect();
+
+        for partnership in event_log.partnerships.iter() {
+            let first_exit = [partnership.individual1, partnership.individual2].iter()
+                .filter_map(|e| deaths.get(e).map(|(time, _)| *time).or(exits.get(e).copied()))
+                .reduce(f32::min);
+            let union_type = if married.contains_key(&partnership.relationship_entity) {
+                UnionType::Marriage
+            } else {
+                partnership.union_type
+            };
+            network.edges.push(NetworkEdge {
+                source: partnership.individual1,
+                target: partnership.individual2,
+                kind: EdgeKind::Partner,
+                union_type: Some(union_type),
+                relationship: Some(partnership.relationship_entity),
+                start: partnership.time,
+                end: ends.get(&partnership.relationship_entity).copied().or(first_exit),
+            });
+        }
+
+        network
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (id, domain, kind) in [
+            ("sex", "node", "string"),
+            ("birth_time", "node", "double"),
+            ("death_time", "node", "double"),
+            ("death_age", "node", "double"),
+            ("exit_time", "node", "double"),
+            ("founder", "node", "boolean"),
+            ("kind", "edge", "string"),
+            ("union_type", "edge", "string"),
+            ("start", "edge", "double"),
+            ("end", "edge", "double"),
+        ] {
+            let _ = writeln!(out, "  <key id=\"{id}\" for=\"{domain}\" attr.name=\"{id}\" attr.type=\"{kind}\"/>");
+        }
+        out.push_str("  <graph id=\"G\" edgedefault=\"directed\">\n");
+
+        for node in self.nodes.iter() {
+            let _ = writeln!(out, "    <node id=\"{}\">", node.entity.to_bits());
+            let _ = writeln!(out, "      <data key=\"sex\">{:?}</data>", node.sex);
+            let _ = writeln!(out, "      <data key=\"birth_time\">{}</data>", node.birth_time);
+            if let (Some(death_time), Some(death_age)) = (node.death_time, node.death_age) {
+                let _ = writeln!(out, "      <data key=\"death_time\">{}</data>", death_time);
+                let _ = writeln!(out, "      <data key=\"death_age\">{}</data>", death_age);
+            }
+            if let Some(exit_time) = node.exit_time {
+                let _ = writeln!(out, "      <data key=\"exit_time\">{}</data>", exit_time);
+            }
+            let _ = writeln!(out, "      <data key=\"founder\">{}</data>", node.founder);
+            out.push_str("    </node>\n");
+        }
+
+        for (i, edge) in self.edges.iter().enumerate() {
+            let _ = writeln!(out, "    <edge id=\"e{}\" source=\"{}\" target=\"{}\" directed=\"{}\">",
+                i, edge.source.to_bits(), edge.target.to_bits(), edge.kind != EdgeKind::Partner);
+            let _ = writeln!(out, "      <data key=\"kind\">{:?}</data>", edge.kind);
+            if let Some(union_type) = edge.union_type {
+                let _ = writeln!(out, "      <data key=\"union_type\">{:?}</data>", union_type);
+            }
+            let _ = writeln!(out, "      <data key=\"start\">{}</data>", edge.start);
+            if let Some(end) = edge.end {
+                let _ = writeln!(out, "      <data key=\"end\">{}</data>", end);
+            }
+            out.push_str("    </edge>\n");
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    // Dynamic GEXF: nodes live from birth to death or emigration, partner edges for the duration of the relationship
+    pub fn to_gexf(&self) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n");
+        out.push_str("  <graph mode=\"dynamic\" defaultedgetype=\"directed\" timeformat=\"double\">\n");
+        out.push_str("    <attributes class=\"node\">\n");
+        out.push_str("      <attribute id=\"sex\" title=\"sex\" type=\"string\"/>\n");
+        out.push_str("      <attribute id=\"death_age\" title=\"death_age\" type=\"double\"/>\n");
+        out.push_str("      <attribute id=\"founder\" title=\"founder\" type=\"boolean\"/>\n");
+        out.push_str("    </attributes>\n");
+        out.push_str("    <attributes class=\"edge\">\n");
+        out.push_str("      <attribute id=\"kind\" title=\"kind\" type=\"string\"/>\n");
+        out.push_str("      <attribute id=\"union_type\" title=\"union_type\" type=\"string\"/>\n");
+        out.push_str("    </attributes>\n");
+
+        out.push_str("    <nodes>\n");
+        for node in self.nodes.iter() {
+            let _ = write!(out, "      <node id=\"{0}\" label=\"{0}\" start=\"{1}\"", node.entity.to_bits(), node.birth_time);
+            if let Some(end) = node.death_time.or(node.exit_time) {
+                let _ = write!(out, " end=\"{}\"", end);
+            }
+            out.push_str(">\n        <attvalues>\n");
+            let _ = writeln!(out, "          <attvalue for=\"sex\" value=\"{:?}\"/>", node.sex);
+            if let Some(death_age) = node.death_age {
+                let _ = writeln!(out, "          <attvalue for=\"death_age\" value=\"{}\"/>", death_age);
+            }
+            let _ = writeln!(out, "          <attvalue for=\"founder\" value=\"{}\"/>", node.founder);
+            out.push_str("        </attvalues>\n      </node>\n");
+        }
+        out.push_str("    </nodes>\n");
+
+        out.push_str("    <edges>\n");
+        for (i, edge) in self.edges.iter().enumerate() {
+            let edge_type = if edge.kind == EdgeKind::Partner { "undirected" } else { "directed" };
+            let _ = write!(out, "      <edge id=\"e{}\" source=\"{}\" target=\"{}\" type=\"{}\" start=\"{}\"",
+                i, edge.source.to_bits(), edge.target.to_bits(), edge_type, edge.start);
+            if let Some(end) = edge.end {
+                let _ = write!(out, " end=\"{}\"", end);
+            }
+            out.push_str(">\n        <attvalues>\n");
+            let _ = writeln!(out, "          <attvalue for=\"kind\" value=\"{:?}\"/>", edge.kind);
+            if let Some(union_type) = edge.union_type {
+                let _ = writeln!(out, "          <attvalue for=\"union_type\" value=\"{:?}\"/>", union_type);
+            }
+            out.push_str("        </attvalues>\n      </edge>\n");
+        }
+        out.push_str("    </edges>\n  </graph>\n</gexf>\n");
+        out
+    }
+
+    pub fn nodes_csv(&self) -> String {
+        let mut out = String::from("entity,sex,birth_time,death_time,death_age,exit_time,founder\n");
+        for node in self.nodes.iter() {
+            let _ = writeln!(out, "{},{:?},{},{},{},{},{}",
+                node.entity.to_bits(), node.sex, node.birth_time,
+                optional(node.death_time), optional(node.death_age), optional(node.exit_time), node.founder);
+        }
+        out
+    }
+
+    pub fn edges_csv(&self) -> String {
+        let mut out = String::from("source,target,kind,union_type,relationship,start,end\n");
+        for edge in self.edges.iter() {
+            let _ = writeln!(out, "{},{},{:?},{},{},{},{}",
+                edge.source.to_bits(), edge.target.to_bits(), edge.kind,
+                edge.union_type.map(|u| format!("{:?}", u)).unwrap_or_default(),
+                optional(edge.relationship.map(|r| r.to_bits())),
+                edge.start, optional(edge.end));
+        }
+        out
+    }
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn write_file(path: &str, contents: &str) {
+    match File::create(path) {
+        Ok(mut file) => {
+            if let Err(e) = file.write_all(contents.as_bytes()) {
+                info!("Error writing {}: {}", path, e);
+            }
+        }
+        Err(e) => info!("Error creating {}: {}", path, e),
+    }
+}
+
+// Writes <prefix>.graphml, <prefix>.gexf, <prefix>_nodes.csv and <prefix>_edges.csv
+pub fn export_network(event_log: &EventLog, prefix: &str) {
+    let network = PedigreeNetwork::from_event_log(event_log);
+    write_file(&format!("{}.graphml", prefix), &network.to_graphml());
+    write_file(&format!("{}.gexf", prefix), &network.to_gexf());
+    write_file(&format!("{}_nodes.csv", prefix), &network.nodes_csv());
+    write_file(&format!("{}_edges.csv", prefix), &network.edges_csv());
+    info!("Exported network with {} nodes and {} edges to {}.graphml/.gexf/_nodes.csv/_edges.csv",
+        network.nodes.len(), network.edges.len(), prefix);
+}
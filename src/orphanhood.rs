@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use serde::Serialize;
+
+use crate::individual::{Individual, Demog, DeathEvent};
+use crate::gestation::{Mother, Father};
+use crate::kinship::{Genealogy, record_emigrations};
+use crate::config::SimulationParameters;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OrphanhoodKind {
+    Maternal,
+    Paternal,
+    Double,
+}
+
+// Emitted when a child under orphan_age_limit loses a parent; kind is the status after this loss
+#[derive(Event, Serialize, Clone)]
+pub struct OrphanhoodEvent {
+    pub child_entity: Entity,
+    pub deceased_parent: Entity,
+    pub kind: OrphanhoodKind,
+    pub child_age: f32,
+    pub time: f32,
+}
+
+pub struct OrphanhoodPlugin;
+
+impl Plugin for OrphanhoodPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<OrphanhoodEvent>()
+
+        //-- ORPHANHOOD
+        // Newborns whose father died in the same step are left to detect_orphans
+        .add_systems(Update, (
+            detect_orphans,
+            detect_posthumous_births.after(record_emigrations).after(detect_orphans),
+        ));
+    }
+}
+
+// ------ ORPHANHOOD ------
+
+// Parental survival status; Mother and Father keep pointing at the deceased parent for lineage
+#[derive(Component, Default, Clone, Copy, PartialEq, Debug)]
+pub struct Orphanhood {
+    pub maternal: bool,
+    pub paternal: bool,
+}
+
+impl Orphanhood {
+    pub fn kind(&self) -> Option<OrphanhoodKind> {
+        match (self.maternal, self.paternal) {
+            (true, true) => Some(OrphanhoodKind::Double),
+            (true, false) => Some(OrphanhoodKind::Maternal),
+            (false, true) => Some(OrphanhoodKind::Paternal),
+            (false, false) => None,
+        }
+    }
+}
+
+fn orphanhood_event(
+    child: Entity,
+    status: Orphanhood,
+    deceased_parent: Entity,
+    age: f32,
+    params: &SimulationParameters,
+    time: f32
+) -> Option<OrphanhoodEvent> {
+    if age >= params.orphan_age_limit {
+        return None;
+    }
+    status.kind().map(|kind| {
+        debug!("{:?} became a {:?} orphan at age {:.1}", child, kind, age);
+        OrphanhoodEvent {
+            child_entity: child,
+            deceased_parent,
+            kind,
+            child_age: age,
+            time,
+        }
+    })
+}
+
+// An individual's parents and what is known of their survival
+type ParentStatus = (Entity, &'static Demog, Option<&'static Mother>, Option<&'static Father>, Option<&'static Orphanhood>);
+
+pub fn detect_orphans(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    mut orphan_events: EventWriter<OrphanhoodEvent>,
+    query: Query<ParentStatus>,
+    params: Res<SimulationParameters>,
+    time: Res<Time>
+) {
+    let deceased: HashSet<Entity> = death_events.read().map(|event| event.entity).collect();
+    if deceased.is_empty() {
+        return;
+    }
+
+    for (e, demog, mother_opt, father_opt, orphanhood_opt) in query.iter() {
+        if deceased.contains(&e) {
+            continue;
+        }
+
+        let dead_mother = mother_opt.map(|mother| mother.0).filter(|mother| deceased.contains(mother));
+        let dead_father = father_opt.map(|father| father.0).filter(|father| deceased.contains(father));
+        if dead_mother.is_none() && dead_father.is_none() {
+            continue;
+        }
+
+        // One event per parent lost, so a child losing both in the same step reports each of them
+        let mut status = orphanhood_opt.copied().unwrap_or_default();
+        if let Some(mother) = dead_mother {
+            status.maternal = true;
+            if let Some(event) = orphanhood_event(e, status, mother, demog.age, &params, time.elapsed_seconds()) {
+                orphan_events.send(event);
+            }
+        }
+        if let Some(father) = dead_father {
+            status.paternal = true;
+            if let Some(event) = orphanhood_event(e, status, father, demog.age, &params, time.elapsed_seconds()) {
+                orphan_events.send(event);
+            }
+        }
+        commands.entity(e).insert(status);
+    }
+}
+
+// Children whose father died during the pregnancy are paternal orphans from birth;
+// a father who emigrated instead is known to the genealogy by his exit
+pub fn detect_posthumous_births(
+    mut commands: Commands,
+    mut orphan_events: EventWriter<OrphanhoodEvent>,
+    query: Query<(Entity, &Demog, &Father, Option<&Orphanhood>), Added<Individual>>,
+    parent_query: Query<&Individual>,
+    genealogy: Option<Res<Genealogy>>,
+    params: Res<SimulationParameters>,
+    time: Res<Time>
+) {
+    for (e, demog, father, orphanhood_opt) in query.iter() {
+        let mut status = orphanhood_opt.copied().unwrap_or_default();
+        if status.paternal {
+            continue;  // already reported by detect_orphans
+        }
+        let emigrated = genealogy.as_deref()
+            .and_then(|genealogy| genealogy.record(father.0))
+            .is_some_and(|record| record.exit_time.is_some());
+        if parent_query.get(father.0).is_err() && !emigrated {
+            status.paternal = true;
+            commands.entity(e).insert(status);
+            if let Some(event) = orphanhood_event(e, status, father.0, demog.age, &params, time.elapsed_seconds()) {
+                orphan_events.send(event);
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::cmp::min;
+use std::collections::{HashMap, HashSet};
 
 use bevy::prelude::*;
 use bevy::time::common_conditions::on_timer;
@@ -8,13 +8,26 @@ use crate::individual::{
     Individual, Demog, Adult, Elder, Sex
 };
 use crate::config::SimulationParameters;
+use crate::location::{Location, SpatialIndex, HOME_SPREAD};
+use crate::patch::{InPatch, LocalParameters};
+use crate::intervention::HazardModifier;
 
 use serde::Serialize;
 
 #[derive(Event, Serialize, Clone)]
 pub struct BreakupEvent {
-    pub male_entity: Entity,
-    pub _female_entity: Entity,
+    pub partner1: Entity,
+    pub partner2: Entity,
+    pub relationship_entity: Entity,
+    pub union_type: UnionType,  // Cohabitation = separation, Marriage = divorce
+    pub location: Option<[f32; 2]>,  // where the couple lived
+    pub time: f32,
+}
+
+#[derive(Event, Serialize, Clone)]
+pub struct MarriageEvent {
+    pub individual1: Entity,
+    pub individual2: Entity,
     pub relationship_entity: Entity,
     pub time: f32,
 }
@@ -24,6 +37,19 @@ pub struct PartnerEvent {
     pub individual1: Entity,
     pub individual2: Entity,
     pub relationship_entity: Entity,
+    pub union_type: UnionType,
+    pub location: Option<[f32; 2]>,  // shared home, None for casual relationships
+    pub distance: Option<f32>,  // between the partners' locations before the union
+    pub time: f32,
+}
+
+// Sexual contact (act) within a relationship, only emitted in concurrent partnership (network) mode
+#[derive(Event, Serialize, Clone)]
+pub struct ContactEvent {
+    pub individual1: Entity,
+    pub individual2: Entity,
+    pub relationship_entity: Entity,
+    pub union_type: UnionType,
     pub time: f32,
 }
 
@@ -45,23 +71,36 @@ impl Plugin for PartnerPlugin {
         .add_event::<BreakupEvent>()
         .add_event::<PartnerEvent>()
         .add_event::<WidowEvent>()
+        .add_event::<MarriageEvent>()
+        .add_event::<ContactEvent>()
         .init_resource::<AvailableSeekers>()
         .add_systems(Update, (
+            assign_orientation,
             start_partner_seeking,
             stop_elder_partner_seeking,
             (
+                update_reentry_delay,
                 queue_partner_seekers,
                 match_partners,
+                random_marriages.before(random_breakups),
                 random_breakups,
+                form_casual_partnerships,
+                dissolve_casual_partnerships,
             ).run_if(on_timer(Duration::from_secs_f32(SEEKING_TIMESTEP))),
+            partnership_contacts.run_if(on_timer(Duration::from_secs_f32(CONTACT_TIMESTEP))),
             resolve_matches,
         ))
-        .add_systems(PostUpdate, detect_widows);
+        .add_systems(PostUpdate, (
+            detect_widows,
+            cleanup_casual_partnerships,
+            prune_partner_lists.after(detect_widows).after(cleanup_casual_partnerships),
+        ));
     }
 }
 
 //-- PARTNERS
 const SEEKING_TIMESTEP: f32 = 1.0/4.0;  // N.B. slower for testing via printout + visualization
+const CONTACT_TIMESTEP: f32 = 1.0/52.0;
 // PARTNER_SEEKING_AGE now comes from SimulationParameters
 
 // ------ PARTNER ------
@@ -69,28 +108,83 @@ const SEEKING_TIMESTEP: f32 = 1.0/4.0;  // N.B. slower for testing via printout
 #[derive(Component)]
 pub struct PartnerSeeking;
 
+#[derive(Component, Debug, Default, PartialEq, Copy, Clone)]
+pub enum Orientation {
+    #[default]
+    Heterosexual,
+    Homosexual,
+    Bisexual,
+}
+
+impl Orientation {
+    pub fn seeks_opposite_sex(&self) -> bool {
+        *self != Orientation::Homosexual
+    }
+
+    pub fn seeks_same_sex(&self) -> bool {
+        *self != Orientation::Heterosexual
+    }
+}
+
 #[derive(Component)]
 pub struct Partner(pub Entity);
 
+#[derive(Component)]
+pub struct Married;
+
+// Remaining years before re-entering the partner market after a breakup or widowhood
+#[derive(Component)]
+pub struct ReentryDelay(pub f32);
+
+// Permanently withdrawn from the partner market after a breakup or widowhood
+#[derive(Component)]
+pub struct NoRepartnering;
+
+// Relationship entities of all the current partnerships of an individual, steady and casual.
+// The steady partner (if any) is also tracked by the Partner component.
+#[derive(Component, Default)]
+pub struct Partners(pub Vec<Entity>);
+
 #[derive(Component)]
 pub struct Relationship;
 
+#[derive(Debug, Default, PartialEq, Copy, Clone, Serialize)]
+pub enum UnionType {
+    #[default]
+    Cohabitation,
+    Marriage,
+    Casual,
+}
+
 #[derive(Component)]
-pub struct Partners {
+pub struct Couple {
     pub e1: Entity,
-    pub e2: Entity
+    pub e2: Entity,
+    pub union_type: UnionType,
 }
 
+// Seekers are queued by the sex of the partner they are looking for.
+// Bisexual seekers are queued in both markets until matched in one of them.
 #[derive(Default, Resource)]
 pub struct AvailableSeekers {
     pub females: Vec<Entity>,
-    pub males: Vec<Entity>
+    pub males: Vec<Entity>,
+    pub same_sex_females: Vec<Entity>,
+    pub same_sex_males: Vec<Entity>,
 }
 impl AvailableSeekers {
-    fn add_seeker(&mut self, e: Entity, sex: Sex) {
-        match sex {
-            Sex::Female => self.females.push(e),
-            _ => self.males.push(e),
+    fn add_seeker(&mut self, e: Entity, sex: Sex, orientation: Orientation) {
+        if orientation.seeks_opposite_sex() {
+            match sex {
+                Sex::Female => self.females.push(e),
+                _ => self.males.push(e),
+            }
+        }
+        if orientation.seeks_same_sex() {
+            match sex {
+                Sex::Female => self.same_sex_females.push(e),
+                _ => self.same_sex_males.push(e),
+            }
         }
     }
     // fn clear(&mut self) {
@@ -100,9 +194,29 @@ impl AvailableSeekers {
 
 }
 
+pub fn assign_orientation(
+    mut commands: Commands,
+    query: Query<Entity, (Added<Individual>, Without<Orientation>)>,
+    params: Res<SimulationParameters>
+) {
+    use rand::prelude::random;
+
+    for e in query.iter() {
+        let draw = random::<f32>();
+        let orientation = if draw < params.homosexual_fraction {
+            Orientation::Homosexual
+        } else if draw < params.homosexual_fraction + params.bisexual_fraction {
+            Orientation::Bisexual
+        } else {
+            Orientation::Heterosexual
+        };
+        commands.entity(e).insert(orientation);
+    }
+}
+
 pub fn start_partner_seeking(
     mut commands: Commands,
-    query: Query<Entity, (Without<PartnerSeeking>, Without<Partner>, With<Adult>, Without<Elder>)>
+    query: Query<Entity, (Without<PartnerSeeking>, Without<Partner>, With<Adult>, Without<Elder>, Without<ReentryDelay>, Without<NoRepartnering>)>
 ) {
     for e in query.iter() {
         debug!("Entity {:?} beginning partner-seeking", e);
@@ -110,6 +224,36 @@ pub fn start_partner_seeking(
     }
 }
 
+pub fn update_reentry_delay(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ReentryDelay)>
+) {
+    for (e, mut delay) in query.iter_mut() {
+        delay.0 -= SEEKING_TIMESTEP;
+        if delay.0 <= 0.0 {
+            debug!("Entity {:?} can re-enter the partner market", e);
+            commands.entity(e).remove::<ReentryDelay>();
+        }
+    }
+}
+
+// Either withdraw an individual permanently from the partner market or hold them out for a while
+pub fn delay_reentry(commands: &mut Commands, e: Entity, age: f32, base_delay: f32, params: &SimulationParameters) {
+    use rand::prelude::random;
+
+    if random::<f32>() < params.no_repartnering_prob {
+        debug!("Entity {:?} will not re-partner", e);
+        commands.entity(e).insert(NoRepartnering);
+        return;
+    }
+
+    let delay = base_delay + params.reentry_delay_age_factor * (age - params.min_partner_seeking_age).max(0.0);
+    if delay > 0.0 {
+        debug!("Entity {:?} waiting {:.2} years to re-enter the partner market", e, delay);
+        commands.entity(e).insert(ReentryDelay(delay));
+    }
+}
+
 pub fn stop_elder_partner_seeking(
     mut commands: Commands,
     query: Query<Entity, (With<PartnerSeeking>, Added<Elder>)>
@@ -122,70 +266,205 @@ pub fn stop_elder_partner_seeking(
 
 pub fn queue_partner_seekers(
     mut cache: ResMut<AvailableSeekers>,
-    query: Query<(Entity, &Demog), 
+    query: Query<(Entity, &Demog, Option<&Orientation>), 
                 //  With<PartnerSeeking>
                  Added<PartnerSeeking>
                  >
 ) {
     // cache.clear();  // TODO: clear and repopulate queues periodically using With<> rather than Added<> query?
 
-    for (e, d) in query.iter() {
-        cache.add_seeker(e, d.sex);
+    for (e, d, orientation_opt) in query.iter() {
+        cache.add_seeker(e, d.sex, orientation_opt.copied().unwrap_or_default());
+    }
+}
+
+// Pair entries of two queues in FIFO order, skipping anyone already matched this step.
+// Passing the same queue twice pairs seekers within a single (same-sex) queue.
+fn match_queues(
+    queue1: &[Entity],
+    queue2: &[Entity],
+    matched: &mut HashSet<Entity>,
+    allowed: impl Fn(Entity, Entity) -> bool
+) -> Vec<(Entity, Entity)> {
+    let mut pairs = Vec::new();
+    let mut first = 0;  // queue2 is matched from the front, so skip the matched prefix once
+    for &e1 in queue1 {
+        if matched.contains(&e1) {
+            continue;
+        }
+        while first < queue2.len() && matched.contains(&queue2[first]) {
+            first += 1;
+        }
+        if first == queue2.len() {
+            break;
+        }
+        let candidate = queue2[first..].iter().copied()
+            .find(|&e2| e2 != e1 && !matched.contains(&e2) && allowed(e1, e2));
+        if let Some(e2) = candidate {
+            matched.insert(e1);
+            matched.insert(e2);
+            pairs.push((e1, e2));
+        }
+    }
+    pairs
+}
+
+// Pair each seeker of queue1 with the earliest-queued unmatched seeker of queue2 within radius.
+// Passing the same queue twice pairs seekers within a single (same-sex) queue.
+fn match_queues_within_radius(
+    queue1: &[Entity],
+    queue2: &[Entity],
+    locations: &HashMap<Entity, Location>,
+    radius: f32,
+    matched: &mut HashSet<Entity>,
+    allowed: impl Fn(Entity, Entity) -> bool
+) -> Vec<(Entity, Entity)> {
+    let mut index = SpatialIndex::new(radius);
+    for (position, e) in queue2.iter().enumerate() {
+        if let Some(location) = locations.get(e) {
+            index.insert((position, *e), location);
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for &e1 in queue1 {
+        if matched.contains(&e1) {
+            continue;
+        }
+        let Some(location) = locations.get(&e1) else {
+            continue;  // seekers without a location can't be matched spatially
+        };
+        let candidate = index.within(location, radius).into_iter()
+            .filter(|(_, e2)| *e2 != e1 && !matched.contains(e2) && allowed(e1, *e2))
+            .min_by_key(|(position, _)| *position);
+        if let Some((_, e2)) = candidate {
+            matched.insert(e1);
+            matched.insert(e2);
+            pairs.push((e1, e2));
+        }
+    }
+    pairs
+}
+
+// Pair the queued seekers, opposite-sex market first, within `radius` when locations are given.
+// `allowed` rules out pairs that can't form a relationship, e.g. couples already together.
+fn pair_seekers(
+    seekers: &AvailableSeekers,
+    locations: Option<&HashMap<Entity, Location>>,
+    radius: f32,
+    matched: &mut HashSet<Entity>,
+    allowed: impl Fn(Entity, Entity) -> bool + Copy
+) -> Vec<(Entity, Entity)> {
+    let markets = [
+        (&seekers.females, &seekers.males),
+        (&seekers.same_sex_females, &seekers.same_sex_females),
+        (&seekers.same_sex_males, &seekers.same_sex_males),
+    ];
+    let mut pairs = Vec::new();
+    for (queue1, queue2) in markets {
+        pairs.extend(match locations {
+            Some(locations) => match_queues_within_radius(queue1, queue2, locations, radius, matched, allowed),
+            None => match_queues(queue1, queue2, matched, allowed),
+        });
+    }
+    pairs
+}
+
+// Add new relationships to each partner's Partners list, creating the list if needed.
+// Links are collected per individual first so two relationships formed in one step both count.
+pub fn link_relationships(commands: &mut Commands, list_query: &mut Query<&mut Partners>, links: HashMap<Entity, Vec<Entity>>) {
+    for (e, relationships) in links {
+        if let Ok(mut partners) = list_query.get_mut(e) {
+            partners.0.extend(relationships);
+        } else if let Some(mut entity) = commands.get_entity(e) {
+            entity.try_insert(Partners(relationships));
+        }
     }
 }
 
 pub fn match_partners(
     mut cache: ResMut<AvailableSeekers>,
     mut commands: Commands,
-    seeker_query: Query<&Individual, (With<PartnerSeeking>, Without<Partner>, Without<Elder>)>
+    seeker_query: Query<&Individual, (With<PartnerSeeking>, Without<Partner>, Without<Elder>)>,
+    location_query: Query<(Entity, &Location), With<PartnerSeeking>>,
+    params: Res<SimulationParameters>
 ) {
     // Filter out invalid entities (dead/elder) and match valid ones
-    let valid_females: Vec<Entity> = cache.females.iter()
-        .filter(|&&e| seeker_query.get(e).is_ok())
-        .copied()
-        .collect();
-
-    let valid_males: Vec<Entity> = cache.males.iter()
-        .filter(|&&e| seeker_query.get(e).is_ok())
-        .copied()
-        .collect();
-
-    // Match valid seekers using FIFO
-    let min_len = min(valid_females.len(), valid_males.len());
-    for i in 0..min_len {
-        let e1 = valid_females[i];
-        let e2 = valid_males[i];
+    let is_valid = |e: &Entity| seeker_query.get(*e).is_ok();
+    let valid = AvailableSeekers {
+        females: cache.females.iter().copied().filter(is_valid).collect(),
+        males: cache.males.iter().copied().filter(is_valid).collect(),
+        same_sex_females: cache.same_sex_females.iter().copied().filter(is_valid).collect(),
+        same_sex_males: cache.same_sex_males.iter().copied().filter(is_valid).collect(),
+    };
+
+    // Match valid seekers using FIFO, opposite-sex market first
+    let mut matched = HashSet::new();
+    let locations: Option<HashMap<Entity, Location>> = params.spatial_matching.then(|| location_query.iter()
+        .map(|(e, location)| (e, *location))
+        .collect());
+    let pairs = pair_seekers(&valid, locations.as_ref(), params.partner_search_radius, &mut matched, |_, _| true);
+
+    for (e1, e2) in pairs {
         commands
             .spawn(Relationship)
-            .insert(Partners{
+            .insert(Couple{
                 e1: e1,
-                e2: e2
+                e2: e2,
+                union_type: UnionType::Cohabitation,  // all unions start as cohabitation
             });
         debug!("New relationship between {:?} and {:?}", e1, e2);
     }
 
     // Update cache: keep only unmatched valid entities
-    cache.females = valid_females.into_iter().skip(min_len).collect();
-    cache.males = valid_males.into_iter().skip(min_len).collect();
+    let unmatched = |e: &Entity| !matched.contains(e);
+    cache.females = valid.females.into_iter().filter(unmatched).collect();
+    cache.males = valid.males.into_iter().filter(unmatched).collect();
+    cache.same_sex_females = valid.same_sex_females.into_iter().filter(unmatched).collect();
+    cache.same_sex_males = valid.same_sex_males.into_iter().filter(unmatched).collect();
 }
 
+// Seekers not yet partnered when their match is resolved
+type UnmatchedSeeker = (Without<Partner>, With<PartnerSeeking>);
+
 pub fn resolve_matches(
     mut commands: Commands,
-    rel_query: Query<(Entity, &Partners), Added<Relationship>>,
-    ind_query: Query<&Individual, (Without<Partner>, With<PartnerSeeking>)>,
+    rel_query: Query<(Entity, &Couple), Added<Relationship>>,
+    ind_query: Query<(&Individual, Option<&Location>), UnmatchedSeeker>,
+    mut list_query: Query<&mut Partners>,
     mut partner_events: EventWriter<PartnerEvent>,
-    time: Res<Time>
+    time: Res<Time>,
+    params: Res<SimulationParameters>
 ) {
+    let mut links: HashMap<Entity, Vec<Entity>> = HashMap::new();
     for (rel_entity, partners) in rel_query.iter() {
-        if let Ok(_ind1) = ind_query.get(partners.e1) {
-            if let Ok(_ind2) = ind_query.get(partners.e2) {
+        if partners.union_type == UnionType::Casual {
+            continue;  // casual relationships are formed directly by form_casual_partnerships
+        }
+        if let Ok((_ind1, location1)) = ind_query.get(partners.e1) {
+            if let Ok((_ind2, location2)) = ind_query.get(partners.e2) {
                 commands.entity(partners.e1).insert(Partner(partners.e2)).remove::<PartnerSeeking>();
                 commands.entity(partners.e2).insert(Partner(partners.e1)).remove::<PartnerSeeking>();
+                links.entry(partners.e1).or_default().push(rel_entity);
+                links.entry(partners.e2).or_default().push(rel_entity);
+
+                // Partners settle in a shared home around their midpoint
+                let (home, distance) = match (location1, location2) {
+                    (Some(l1), Some(l2)) => (Some(l1.midpoint(l2).near(HOME_SPREAD, &params)), Some(l1.distance(l2))),
+                    _ => (None, None),
+                };
+                if let Some(home) = home {
+                    commands.entity(partners.e1).insert(home);
+                    commands.entity(partners.e2).insert(home);
+                }
 
                 partner_events.send(PartnerEvent {
                     individual1: partners.e1,
                     individual2: partners.e2,
                     relationship_entity: rel_entity,
+                    union_type: partners.union_type,
+                    location: home.map(|l| l.coords()),
+                    distance,
                     time: time.elapsed_seconds(),
                 });
             } else {
@@ -199,45 +478,94 @@ pub fn resolve_matches(
             commands.entity(partners.e2).remove::<PartnerSeeking>();  // will be added back to try again at finding a partner
         }
     }
+    link_relationships(&mut commands, &mut list_query, links);
+}
+
+pub fn random_marriages(
+    mut commands: Commands,
+    mut rel_query: Query<(Entity, &mut Couple), With<Relationship>>,
+    mut marriage_events: EventWriter<MarriageEvent>,
+    patch_query: Query<&InPatch>,
+    time: Res<Time>,
+    params: LocalParameters
+) {
+    use rand::prelude::random;
+
+    for (rel_entity, mut partners) in rel_query.iter_mut() {
+        // Convert marriage_rate (per year) to probability per SEEKING_TIMESTEP, using the rate of the couple's patch
+        let params = params.get(patch_query.get(partners.e1).ok());
+        let marriage_prob = 1.0 - (-SEEKING_TIMESTEP * params.marriage_rate).exp();
+        if partners.union_type == UnionType::Cohabitation && random::<f32>() < marriage_prob {
+            debug!("Cohabiting couple {:?} and {:?} got married", partners.e1, partners.e2);
+            partners.union_type = UnionType::Marriage;
+
+            // Either partner may have died this frame, before detect_widows ends the relationship
+            for e in [partners.e1, partners.e2] {
+                if let Some(mut entity) = commands.get_entity(e) {
+                    entity.try_insert(Married);
+                }
+            }
+
+            marriage_events.send(MarriageEvent {
+                individual1: partners.e1,
+                individual2: partners.e2,
+                relationship_entity: rel_entity,
+                time: time.elapsed_seconds(),
+            });
+        }
+    }
 }
 
+// A partner's age, place and any intervention scaling the breakup rate
+type PartnerRisk = (&'static Demog, Option<&'static Location>, Option<&'static InPatch>, Option<&'static HazardModifier>);
+
 pub fn random_breakups(
     mut commands: Commands,
-    rel_query: Query<(Entity, &Partners), With<Relationship>>,
-    demog_query: Query<&Demog>,
+    rel_query: Query<(Entity, &Couple), With<Relationship>>,
+    demog_query: Query<PartnerRisk>,
     mut breakup_events: EventWriter<BreakupEvent>,
     time: Res<Time>,
-    params: Res<SimulationParameters>
+    params: LocalParameters
 ) {
     use rand::prelude::random;
 
-    // Convert breakup_rate (per year) to probability per SEEKING_TIMESTEP (quarterly check)
+    // Convert separation (breakup_rate) and divorce_rate (per year) to probability per SEEKING_TIMESTEP (quarterly check)
     // Using same exponential conversion as conception: prob = 1 - exp(-timestep * rate)
     for (rel_entity, partners) in rel_query.iter() {
-        let breakup_prob = 1.0 - (-SEEKING_TIMESTEP * params.breakup_rate).exp();
+        let patch_opt = demog_query.get(partners.e1).ok().and_then(|(_, _, patch, _)| patch);
+        let params = params.get(patch_opt);
+        // Interventions on either partner scale the rate
+        let modifier: f32 = [partners.e1, partners.e2].iter()
+            .filter_map(|e| demog_query.get(*e).ok().and_then(|(_, _, _, modifier)| modifier))
+            .map(|m| m.breakup)
+            .product();
+        let rate = match partners.union_type {
+            UnionType::Cohabitation => params.breakup_rate,
+            UnionType::Marriage => params.divorce_rate,
+            UnionType::Casual => continue,  // see dissolve_casual_partnerships
+        } * modifier;
+        let breakup_prob = 1.0 - (-SEEKING_TIMESTEP * rate).exp();
         if random::<f32>() < breakup_prob {
-            debug!("Relationship between {:?} and {:?} ended in breakup", partners.e1, partners.e2);
-
-            // Determine which is male and which is female for the event
-            if let (Ok(demog1), Ok(_demog2)) = (demog_query.get(partners.e1), demog_query.get(partners.e2)) {
-                let (male_entity, female_entity) = if demog1.sex == Sex::Male {
-                    (partners.e1, partners.e2)
-                } else {
-                    (partners.e2, partners.e1)
-                };
+            debug!("{:?} between {:?} and {:?} ended in breakup", partners.union_type, partners.e1, partners.e2);
 
+            if let (Ok((demog1, location1, _, _)), Ok((demog2, _, _, _))) = (demog_query.get(partners.e1), demog_query.get(partners.e2)) {
                 // Send breakup event for other systems to handle
                 breakup_events.send(BreakupEvent {
-                    male_entity,
-                    _female_entity: female_entity,
+                    partner1: partners.e1,
+                    partner2: partners.e2,
                     relationship_entity: rel_entity,
+                    union_type: partners.union_type,
+                    location: location1.map(|l| l.coords()),
                     time: time.elapsed_seconds(),
                 });
+
+                delay_reentry(&mut commands, partners.e1, demog1.age, params.breakup_reentry_delay, params);
+                delay_reentry(&mut commands, partners.e2, demog2.age, params.breakup_reentry_delay, params);
             }
 
-            // Remove Partner components from both entities (they'll re-enter partner seeking)
-            commands.entity(partners.e1).remove::<Partner>();
-            commands.entity(partners.e2).remove::<Partner>();
+            // Remove Partner components from both entities (they'll re-enter partner seeking after any delay)
+            commands.entity(partners.e1).remove::<(Partner, Married)>();
+            commands.entity(partners.e2).remove::<(Partner, Married)>();
 
             // Despawn the relationship entity
             commands.entity(rel_entity).despawn();
@@ -248,21 +576,27 @@ pub fn random_breakups(
 pub fn detect_widows(
     mut commands: Commands,
     mut removals: RemovedComponents<Partner>,
-    rel_query: Query<(Entity, &Partners), With<Relationship>>,
-    entity_query: Query<Entity>,
+    rel_query: Query<(Entity, &Couple), With<Relationship>>,
+    demog_query: Query<(&Demog, Option<&InPatch>)>,
     mut widow_events: EventWriter<WidowEvent>,
-    time: Res<Time>
+    time: Res<Time>,
+    params: LocalParameters
 ) {
     for dead_entity in removals.read() {
         debug!("{:?} detected removal of Partner component", dead_entity);
 
         // Find the relationship entity that contains this dead entity
         for (rel_entity, partners) in rel_query.iter() {
+            if partners.union_type == UnionType::Casual {
+                continue;  // see cleanup_casual_partnerships
+            }
             if partners.e1 == dead_entity {
                 debug!("{:?} died + notified their partner {:?}", dead_entity, partners.e2);
                 // Only try to remove Partner component if the partner entity still exists
-                if entity_query.get(partners.e2).is_ok() {
-                    commands.entity(partners.e2).remove::<Partner>();
+                if let Ok((demog, patch_opt)) = demog_query.get(partners.e2) {
+                    let params = params.get(patch_opt);
+                    commands.entity(partners.e2).remove::<(Partner, Married)>();
+                    delay_reentry(&mut commands, partners.e2, demog.age, params.widowhood_reentry_delay, params);
 
                     widow_events.send(WidowEvent {
                         widow_entity: partners.e2,
@@ -276,8 +610,10 @@ pub fn detect_widows(
             } else if partners.e2 == dead_entity {
                 debug!("{:?} died + notified their partner {:?}", dead_entity, partners.e1);
                 // Only try to remove Partner component if the partner entity still exists
-                if entity_query.get(partners.e1).is_ok() {
-                    commands.entity(partners.e1).remove::<Partner>();
+                if let Ok((demog, patch_opt)) = demog_query.get(partners.e1) {
+                    let params = params.get(patch_opt);
+                    commands.entity(partners.e1).remove::<(Partner, Married)>();
+                    delay_reentry(&mut commands, partners.e1, demog.age, params.widowhood_reentry_delay, params);
 
                     widow_events.send(WidowEvent {
                         widow_entity: partners.e1,
@@ -291,4 +627,193 @@ pub fn detect_widows(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+// ------ CONCURRENT PARTNERSHIPS ------
+
+// An adult who may take a casual partner, with where they live
+type CasualCandidate = (Entity, &'static Demog, Option<&'static Orientation>, Option<&'static Location>, Option<&'static InPatch>);
+
+pub fn form_casual_partnerships(
+    mut commands: Commands,
+    ind_query: Query<CasualCandidate, (With<Adult>, Without<Elder>)>,
+    rel_query: Query<&Couple, With<Relationship>>,
+    mut list_query: Query<&mut Partners>,
+    mut partner_events: EventWriter<PartnerEvent>,
+    time: Res<Time>,
+    params: LocalParameters
+) {
+    use rand::prelude::random;
+    use rand::seq::SliceRandom;
+
+    if !params.global().concurrent_partnerships {
+        return;
+    }
+
+    // Current relationships (steady or casual) per individual, and pairs that can't form another one
+    let mut current: HashMap<Entity, usize> = HashMap::new();
+    let mut existing: HashSet<(Entity, Entity)> = HashSet::new();
+    for couple in rel_query.iter() {
+        *current.entry(couple.e1).or_default() += 1;
+        *current.entry(couple.e2).or_default() += 1;
+        existing.insert((couple.e1, couple.e2));
+        existing.insert((couple.e2, couple.e1));
+    }
+
+    // Individuals below the concurrency cap look for a casual partner at casual_formation_rate,
+    // queued in random order by the sex of the partner they are looking for
+    let mut seekers: Vec<_> = ind_query.iter()
+        .filter(|(e, _, _, _, patch_opt)| {
+            let params = params.get(*patch_opt);
+            let formation_prob = 1.0 - (-SEEKING_TIMESTEP * params.casual_formation_rate).exp();
+            current.get(e).copied().unwrap_or(0) < params.max_concurrent_partners && random::<f32>() < formation_prob
+        })
+        .collect();
+    seekers.shuffle(&mut rand::thread_rng());
+
+    let mut queues = AvailableSeekers::default();
+    let mut locations: HashMap<Entity, Location> = HashMap::new();
+    for (e, demog, orientation_opt, location_opt, _) in seekers {
+        queues.add_seeker(e, demog.sex, orientation_opt.copied().unwrap_or_default());
+        if let Some(location) = location_opt {
+            locations.insert(e, *location);
+        }
+    }
+
+    let mut matched = HashSet::new();
+    let pairs = pair_seekers(
+        &queues,
+        params.global().spatial_matching.then_some(&locations),
+        params.global().partner_search_radius,
+        &mut matched,
+        |e1, e2| !existing.contains(&(e1, e2))
+    );
+
+    let mut links: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    for (e1, e2) in pairs {
+        let rel_entity = commands
+            .spawn(Relationship)
+            .insert(Couple{
+                e1,
+                e2,
+                union_type: UnionType::Casual,
+            })
+            .id();
+        debug!("New casual relationship {:?} between {:?} and {:?}", rel_entity, e1, e2);
+        links.entry(e1).or_default().push(rel_entity);
+        links.entry(e2).or_default().push(rel_entity);
+
+        partner_events.send(PartnerEvent {
+            individual1: e1,
+            individual2: e2,
+            relationship_entity: rel_entity,
+            union_type: UnionType::Casual,
+            location: None,
+            distance: locations.get(&e1)
+                .zip(locations.get(&e2))
+                .map(|(l1, l2)| l1.distance(l2)),
+            time: time.elapsed_seconds(),
+        });
+    }
+    link_relationships(&mut commands, &mut list_query, links);
+}
+
+pub fn dissolve_casual_partnerships(
+    mut commands: Commands,
+    rel_query: Query<(Entity, &Couple), With<Relationship>>,
+    patch_query: Query<&InPatch>,
+    mut breakup_events: EventWriter<BreakupEvent>,
+    time: Res<Time>,
+    params: LocalParameters
+) {
+    use rand::prelude::random;
+
+    for (rel_entity, partners) in rel_query.iter() {
+        if partners.union_type != UnionType::Casual {
+            continue;
+        }
+        // The rate of the first partner's patch, as for marriages
+        let params = params.get(patch_query.get(partners.e1).ok());
+        let dissolution_prob = 1.0 - (-SEEKING_TIMESTEP * params.casual_dissolution_rate).exp();
+        if random::<f32>() < dissolution_prob {
+            debug!("Casual relationship between {:?} and {:?} ended", partners.e1, partners.e2);
+
+            breakup_events.send(BreakupEvent {
+                partner1: partners.e1,
+                partner2: partners.e2,
+                relationship_entity: rel_entity,
+                union_type: UnionType::Casual,
+                location: None,
+                time: time.elapsed_seconds(),
+            });
+
+            commands.entity(rel_entity).despawn();
+        }
+    }
+}
+
+pub fn cleanup_casual_partnerships(
+    mut commands: Commands,
+    rel_query: Query<(Entity, &Couple), With<Relationship>>,
+    entity_query: Query<Entity>
+) {
+    // Casual relationships don't produce widowings; just drop those whose partner is gone
+    for (rel_entity, partners) in rel_query.iter() {
+        if partners.union_type != UnionType::Casual {
+            continue;
+        }
+        if entity_query.get(partners.e1).is_err() || entity_query.get(partners.e2).is_err() {
+            debug!("Casual relationship {:?} ended as a partner is gone", rel_entity);
+            commands.entity(rel_entity).despawn();
+        }
+    }
+}
+
+// Drop ended relationships (breakups, dissolutions, deaths and emigration) from the partners' lists
+pub fn prune_partner_lists(
+    mut removals: RemovedComponents<Relationship>,
+    mut list_query: Query<&mut Partners>
+) {
+    let ended: HashSet<Entity> = removals.read().collect();
+    if ended.is_empty() {
+        return;
+    }
+    for mut partners in list_query.iter_mut() {
+        if partners.0.iter().any(|r| ended.contains(r)) {
+            partners.0.retain(|r| !ended.contains(r));
+        }
+    }
+}
+
+pub fn partnership_contacts(
+    rel_query: Query<(Entity, &Couple), With<Relationship>>,
+    patch_query: Query<&InPatch>,
+    mut contact_events: EventWriter<ContactEvent>,
+    time: Res<Time>,
+    params: LocalParameters
+) {
+    use rand::prelude::random;
+
+    if !params.global().concurrent_partnerships {
+        return;
+    }
+
+    // At most one act per relationship per CONTACT_TIMESTEP
+    for (rel_entity, partners) in rel_query.iter() {
+        let params = params.get(patch_query.get(partners.e1).ok());
+        let rate = match partners.union_type {
+            UnionType::Casual => params.casual_contact_rate,
+            _ => params.steady_contact_rate,
+        };
+        let contact_prob = 1.0 - (-CONTACT_TIMESTEP * rate).exp();
+        if random::<f32>() < contact_prob {
+            contact_events.send(ContactEvent {
+                individual1: partners.e1,
+                individual2: partners.e2,
+                relationship_entity: rel_entity,
+                union_type: partners.union_type,
+                time: time.elapsed_seconds(),
+            });
+        }
+    }
+}
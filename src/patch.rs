@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use rand::prelude::random;
+
+use serde::{Deserialize, Serialize};
+
+use crate::individual::{Individual, Adult};
+use crate::partner::Partner;
+use crate::household::{HouseholdMember, LeftHome, DependentChildren, new_household, move_with_children};
+use crate::location::Location;
+use crate::config::SimulationParameters;
+
+// Emitted whenever an individual's patch changes, through migration or by moving house across a patch boundary
+#[derive(Event, Serialize, Clone)]
+pub struct MigrationEvent {
+    pub entity: Entity,
+    pub household: Option<Entity>,
+    pub from_patch: usize,
+    pub to_patch: usize,
+    pub location: [f32; 2],
+    pub time: f32,
+}
+
+pub struct PatchPlugin;
+
+impl Plugin for PatchPlugin {
+    fn build(&self, app: &mut App) {
+        // Patches are inserted by the caller (from --patches or the Python dict), otherwise there are none
+        app
+            .init_resource::<Patches>()
+            .add_event::<MigrationEvent>()
+            .init_resource::<PatchParameters>()
+            .add_systems(PreUpdate, refresh_patch_parameters)
+
+        //-- PATCHES
+        .add_systems(Update, (
+            assign_patch,
+            migrate.run_if(on_timer(Duration::from_secs_f32(MIGRATION_TIMESTEP))),
+        ))
+        .add_systems(bevy::app::Last, print_patch_summary);
+    }
+}
+
+//-- PATCHES
+const MIGRATION_TIMESTEP: f32 = 1.0/12.0;
+const PLACEMENT_ATTEMPTS: usize = 100;  // draws within a patch's radius before settling at its centre
+
+// Parameters read once for the whole population, at startup or before anyone is in a patch;
+// every other numeric parameter can be overridden per patch
+pub const GLOBAL_PARAMETERS: &[&str] = &[
+    "spawn_individual_age",
+    "initial_sex_ratio",
+    "homosexual_fraction",
+    "bisexual_fraction",
+    "initial_prevalence",
+    "grid_width",
+    "grid_height",
+    "density_dependence",
+    "carrying_capacity",
+    "density_cell_size",
+    "density_fertility_strength",
+    "density_mortality_strength",
+    "spatial_matching",
+    "partner_search_radius",
+    "concurrent_partnerships",
+    "orphan_age_limit",
+];
+
+// ------ PATCHES ------
+
+// A village or region: individuals living closest to its centre belong to it
+#[derive(Deserialize, Clone, Debug)]
+pub struct Patch {
+    pub name: String,
+    pub center: [f32; 2],
+    pub radius: f32,  // founders and migrants settle within this distance of the centre, where it's nearest
+    #[serde(default = "default_share")]
+    pub share: f32,  // relative share of the initial population
+    #[serde(default)]
+    pub overrides: HashMap<String, f32>,  // SimulationParameters field name -> value in this patch
+}
+
+fn default_share() -> f32 { 1.0 }
+
+impl Patch {
+    pub fn center(&self) -> Location {
+        Location(Vec2::from_array(self.center))
+    }
+
+
+    // The global parameters with this patch's overrides applied
+    pub fn parameters(&self, base: &SimulationParameters) -> SimulationParameters {
+        let mut params = base.clone();
+        for (name, value) in self.overrides.iter() {
+            if let Err(e) = params.set_by_name(name, *value) {
+                warn!("Patch {}: {}", self.name, e);
+            }
+        }
+        params
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationModel {
+    // Annual per-capita rate of moving from patch i (row) to patch j (column)
+    Rates(Vec<Vec<f32>>),
+    // Rate from i to j is scale * population_j / distance_ij^exponent
+    Gravity { scale: f32, exponent: f32 },
+}
+
+#[derive(Resource, Deserialize, Clone, Default, Debug)]
+pub struct Patches {
+    pub patches: Vec<Patch>,
+    #[serde(default)]
+    pub migration: Option<MigrationModel>,
+    #[serde(default)]
+    pub household_migration: bool,  // whole households move together instead of single adults
+}
+
+impl Patches {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let patches: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        patches.validate()?;
+        Ok(patches)
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        Self::from_json(&fs::read_to_string(path).map_err(|e| e.to_string())?)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let mut params = SimulationParameters::default();
+        for patch in self.patches.iter() {
+            for (name, value) in patch.overrides.iter() {
+                if GLOBAL_PARAMETERS.contains(&name.as_str()) {
+                    return Err(format!("patch {}: '{}' applies to the whole population and can't be overridden per patch", patch.name, name));
+                }
+                params.set_by_name(name, *value).map_err(|e| format!("patch {}: {}", patch.name, e))?;
+            }
+        }
+        if let Some(MigrationModel::Rates(rates)) = &self.migration {
+            if rates.len() != self.patches.len() || rates.iter().any(|row| row.len() != self.patches.len()) {
+                return Err(format!("migration rates must be a {0}x{0} matrix", self.patches.len()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn nearest(&self, location: &Location) -> Option<usize> {
+        self.patches.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.center().distance(location).total_cmp(&b.center().distance(location)))
+            .map(|(i, _)| i)
+    }
+
+    // Uniformly within the radius of patch `i`, redrawn where an overlapping patch's centre is nearer
+    pub fn place(&self, i: usize, params: &SimulationParameters) -> Location {
+        let patch = &self.patches[i];
+        for _ in 0..PLACEMENT_ATTEMPTS {
+            let location = patch.center().within(patch.radius, params);
+            if self.nearest(&location) == Some(i) {
+                return location;
+            }
+        }
+        Location::clamped(patch.center().0, params)
+    }
+
+    // Patch drawn in proportion to the population shares
+    pub fn draw_patch(&self) -> usize {
+        let total: f32 = self.patches.iter().map(|p| p.share).sum();
+        let mut draw = random::<f32>() * total;
+        for (i, patch) in self.patches.iter().enumerate() {
+            if draw < patch.share {
+                return i;
+            }
+            draw -= patch.share;
+        }
+        self.patches.len() - 1
+    }
+
+    // Founders are spread over the patches by share, or over the whole grid without patches
+    pub fn place_founder(&self, params: &SimulationParameters) -> Location {
+        if self.patches.is_empty() {
+            return Location::random(params);
+        }
+        self.place(self.draw_patch(), params)
+    }
+
+    // Annual per-capita migration rates between patches given their current populations
+    pub fn migration_rates(&self, populations: &[usize]) -> Vec<Vec<f32>> {
+        let n = self.patches.len();
+        match &self.migration {
+            None => vec![vec![0.0; n]; n],
+            Some(MigrationModel::Rates(rates)) => rates.clone(),
+            Some(MigrationModel::Gravity { scale, exponent }) => (0..n)
+                .map(|i| (0..n)
+                    .map(|j| {
+                        if i == j {
+                            return 0.0;
+                        }
+                        let distance = self.patches[i].center().distance(&self.patches[j].center()).max(f32::EPSILON);
+                        scale * populations[j] as f32 / distance.powf(*exponent)
+                    })
+                    .collect())
+                .collect(),
+        }
+    }
+}
+
+// Per-patch parameters, the global SimulationParameters with each patch's overrides applied
+#[derive(Resource, Default)]
+pub struct PatchParameters(pub Vec<SimulationParameters>);
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InPatch(pub usize);
+
+// Parameters in effect for an individual: their patch's, or the global ones outside any patch
+fn local_params<'a>(
+    params: &'a SimulationParameters,
+    patch_params: Option<&'a PatchParameters>,
+    patch: Option<&InPatch>
+) -> &'a SimulationParameters {
+    match (patch_params, patch) {
+        (Some(patch_params), Some(patch)) => patch_params.0.get(patch.0).unwrap_or(params),
+        _ => params,
+    }
+}
+
+// Global and per-patch parameters together, for systems that look up those in effect for each individual
+#[derive(SystemParam)]
+pub struct LocalParameters<'w> {
+    global: Res<'w, SimulationParameters>,
+    patches: Option<Res<'w, PatchParameters>>,
+}
+
+impl LocalParameters<'_> {
+    pub fn global(&self) -> &SimulationParameters {
+        &self.global
+    }
+
+    pub fn get(&self, patch: Option<&InPatch>) -> &SimulationParameters {
+        local_params(&self.global, self.patches.as_deref(), patch)
+    }
+}
+
+pub fn refresh_patch_parameters(
+    mut patch_params: ResMut<PatchParameters>,
+    patches: Res<Patches>,
+    params: Res<SimulationParameters>
+) {
+    if patches.is_changed() || params.is_changed() {
+        patch_params.0 = patches.patches.iter().map(|patch| patch.parameters(&params)).collect();
+    }
+}
+
+// An individual's position, the patch they were last in, and their household
+type Whereabouts = (Entity, &'static Location, Option<&'static InPatch>, Option<&'static HouseholdMember>);
+
+pub fn assign_patch(
+    mut commands: Commands,
+    query: Query<Whereabouts, (With<Individual>, Changed<Location>)>,
+    patches: Res<Patches>,
+    mut migration_events: EventWriter<MigrationEvent>,
+    time: Res<Time>
+) {
+    for (e, location, patch_opt, member_opt) in query.iter() {
+        let Some(patch) = patches.nearest(location) else {
+            return;
+        };
+        match patch_opt {
+            Some(current) if current.0 == patch => continue,
+            Some(current) => {
+                debug!("{:?} moved from patch {} to patch {}", e, current.0, patch);
+                migration_events.send(MigrationEvent {
+                    entity: e,
+                    household: member_opt.map(|m| m.0),
+                    from_patch: current.0,
+                    to_patch: patch,
+                    location: location.coords(),
+                    time: time.elapsed_seconds(),
+                });
+            }
+            None => {}
+        }
+        commands.entity(e).insert(InPatch(patch));
+    }
+}
+
+// Destination for a migrant leaving `from` this timestep, if they move at all
+fn draw_destination(rates: &[Vec<f32>], from: usize) -> Option<usize> {
+    let total: f32 = rates[from].iter()
+        .enumerate()
+        .filter(|(j, _)| *j != from)
+        .map(|(_, rate)| rate)
+        .sum();
+    let migration_prob = 1.0 - (-MIGRATION_TIMESTEP * total).exp();
+    if total <= 0.0 || random::<f32>() >= migration_prob {
+        return None;
+    }
+
+    let mut draw = random::<f32>() * total;
+    for (j, rate) in rates[from].iter().enumerate() {
+        if j == from {
+            continue;
+        }
+        if draw < *rate {
+            return Some(j);
+        }
+        draw -= rate;
+    }
+    None
+}
+
+// A resident of a patch and the household ties that decide who moves with them
+type Resident = (Entity, &'static InPatch, Option<&'static HouseholdMember>, Option<&'static Adult>, Option<&'static Partner>);
+
+pub fn migrate(
+    mut commands: Commands,
+    query: Query<Resident>,
+    children_query: DependentChildren,
+    patches: Res<Patches>,
+    params: LocalParameters
+) {
+    if patches.migration.is_none() {
+        return;
+    }
+
+    let mut populations = vec![0; patches.patches.len()];
+    for (_, patch, _, _, _) in query.iter() {
+        populations[patch.0] += 1;
+    }
+    let rates = patches.migration_rates(&populations);
+
+    if patches.household_migration {
+        // Every household decides once, by the patch of its first member found
+        let mut households: HashMap<Entity, (usize, Vec<Entity>)> = HashMap::new();
+        for (e, patch, member_opt, _, _) in query.iter() {
+            if let Some(member) = member_opt {
+                households.entry(member.0).or_insert((patch.0, Vec::new())).1.push(e);
+            }
+        }
+        for (household, (from, members)) in households.into_iter() {
+            if let Some(to) = draw_destination(&rates, from) {
+                let location = patches.place(to, params.global());
+                debug!("Household {:?} migrated from patch {} to patch {}", household, from, to);
+                commands.entity(household).insert(location);
+                for e in members {
+                    commands.entity(e).insert(location);
+                }
+            }
+        }
+    } else {
+        // Unpartnered adults move on their own into a new household, taking their dependent children
+        for (e, patch, member_opt, adult_opt, partner_opt) in query.iter() {
+            if adult_opt.is_none() || partner_opt.is_some() {
+                continue;
+            }
+            if let Some(to) = draw_destination(&rates, patch.0) {
+                let location = patches.place(to, params.global());
+                let household = new_household(&mut commands, Some(location));
+                debug!("{:?} migrated from patch {} to patch {}", e, patch.0, to);
+                move_with_children(&mut commands, e, member_opt.map(|m| m.0), household, Some(location), &children_query);
+                commands.entity(e).insert(LeftHome);
+            }
+        }
+    }
+}
+
+fn print_patch_summary(
+    query: Query<&InPatch>,
+    patches: Res<Patches>,
+    exit_events: EventReader<bevy::app::AppExit>
+) {
+    if !exit_events.is_empty() && !patches.patches.is_empty() {
+        let mut populations = vec![0; patches.patches.len()];
+        for patch in query.iter() {
+            populations[patch.0] += 1;
+        }
+
+        info!("\n========== PATCH SUMMARY ==========");
+        for (patch, population) in patches.patches.iter().zip(populations) {
+            info!("{:<20} {}", patch.name, population);
+        }
+        info!("===================================\n");
+    }
+}
@@ -8,19 +8,34 @@ use std::sync::{Arc, Mutex};
 
 use crate::individual::IndividualPlugin;
 use crate::partner::PartnerPlugin;
-use crate::gestation::GestationPlugin;
-use crate::config::{ConfigPlugin, Args, SimulationParameters};
+use crate::gestation::{GestationPlugin, PregnancyLossHazard, MultipleBirthRate};
+use crate::household::HouseholdPlugin;
+use crate::config::{ConfigPlugin, Args, SimulationParameters, CustodyRule};
 use crate::events::{EventLogPlugin, EventLog};
+use crate::census::{CensusPlugin, Census};
+use crate::orphanhood::OrphanhoodPlugin;
+use crate::kinship::{KinshipPlugin, Genealogy};
+use crate::patch::{PatchPlugin, Patches};
+use crate::emigration::{EmigrationPlugin, EmigrationRate};
+use crate::density::DensityPlugin;
+use crate::scenario::{ScenarioPlugin, Scenario};
+use crate::intervention::{InterventionPlugin, AddIntervention, FamilyPlanning};
+use crate::infection::{InfectionPlugin, VERTICAL_INFECTION, MATERNAL_ANTIBODIES};
+use crate::vaccination::{VaccinationPlugin, RoutineVaccination, VaccinationCampaign};
 
-/// Resource to capture EventLog before app exits
+/// Resource to capture EventLog, Census and Genealogy before app exits
 #[derive(Resource)]
 struct EventLogCapture {
     captured: Arc<Mutex<Option<EventLog>>>,
+    captured_census: Arc<Mutex<Option<Census>>>,
+    captured_genealogy: Arc<Mutex<Option<Genealogy>>>,
 }
 
-/// System to capture EventLog on exit
+/// System to capture EventLog, Census and Genealogy on exit
 fn capture_event_log_on_exit(
     event_log: Res<EventLog>,
+    census: Res<Census>,
+    genealogy: Res<Genealogy>,
     exit_events: EventReader<bevy::app::AppExit>,
     capture: Res<EventLogCapture>,
 ) {
@@ -29,11 +44,27 @@ fn capture_event_log_on_exit(
         let log_data = EventLog {
             births: event_log.births.clone(),
             deaths: event_log.deaths.clone(),
+            stillbirths: event_log.stillbirths.clone(),
+            conceptions: event_log.conceptions.clone(),
+            pregnancy_ends: event_log.pregnancy_ends.clone(),
+            completed_fertility: event_log.completed_fertility.clone(),
             partnerships: event_log.partnerships.clone(),
+            marriages: event_log.marriages.clone(),
             breakups: event_log.breakups.clone(),
             widowings: event_log.widowings.clone(),
+            contacts: event_log.contacts.clone(),
+            orphanings: event_log.orphanings.clone(),
+            custody: event_log.custody.clone(),
+            migrations: event_log.migrations.clone(),
+            emigrations: event_log.emigrations.clone(),
+            parameter_changes: event_log.parameter_changes.clone(),
+            enrolments: event_log.enrolments.clone(),
+            infections: event_log.infections.clone(),
+            vaccinations: event_log.vaccinations.clone(),
         };
         *capture.captured.lock().unwrap() = Some(log_data);
+        *capture.captured_census.lock().unwrap() = Some(census.clone());
+        *capture.captured_genealogy.lock().unwrap() = Some(genealogy.clone());
     }
 }
 
@@ -45,6 +76,30 @@ fn events_to_births_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
     let mother_entities: Vec<Option<u64>> = event_log.births.iter()
         .map(|e| e.mother_entity.map(|m| m.to_bits()))
         .collect();
+    let father_entities: Vec<Option<u64>> = event_log.births.iter()
+        .map(|e| e.father_entity.map(|f| f.to_bits()))
+        .collect();
+    let pregnancy_ids: Vec<Option<u32>> = event_log.births.iter()
+        .map(|e| e.pregnancy_id)
+        .collect();
+    let sexes: Vec<String> = event_log.births.iter()
+        .map(|e| format!("{:?}", e.sex))
+        .collect();
+    let infected_at_birth: Vec<bool> = event_log.births.iter()
+        .map(|e| e.maternal_transfer.received(VERTICAL_INFECTION))
+        .collect();
+    let maternal_antibodies: Vec<bool> = event_log.births.iter()
+        .map(|e| e.maternal_transfer.received(MATERNAL_ANTIBODIES))
+        .collect();
+    let maternal_transfers: Vec<String> = event_log.births.iter()
+        .map(|e| e.maternal_transfer.0.join(","))
+        .collect();
+    let xs: Vec<f32> = event_log.births.iter()
+        .map(|e| e.location[0])
+        .collect();
+    let ys: Vec<f32> = event_log.births.iter()
+        .map(|e| e.location[1])
+        .collect();
     let times: Vec<f32> = event_log.births.iter()
         .map(|e| e.time)
         .collect();
@@ -52,6 +107,14 @@ fn events_to_births_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
     DataFrame::new(vec![
         Series::new("child_entity".into(), child_entities),
         Series::new("mother_entity".into(), mother_entities),
+        Series::new("father_entity".into(), father_entities),
+        Series::new("pregnancy_id".into(), pregnancy_ids),
+        Series::new("sex".into(), sexes),
+        Series::new("infected_at_birth".into(), infected_at_birth),
+        Series::new("maternal_antibodies".into(), maternal_antibodies),
+        Series::new("maternal_transfer".into(), maternal_transfers),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
         Series::new("time".into(), times),
     ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
 }
@@ -64,6 +127,15 @@ fn events_to_deaths_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
     let ages: Vec<f32> = event_log.deaths.iter()
         .map(|e| e.age)
         .collect();
+    let causes: Vec<String> = event_log.deaths.iter()
+        .map(|e| e.cause.name().to_string())
+        .collect();
+    let xs: Vec<Option<f32>> = event_log.deaths.iter()
+        .map(|e| e.location.map(|l| l[0]))
+        .collect();
+    let ys: Vec<Option<f32>> = event_log.deaths.iter()
+        .map(|e| e.location.map(|l| l[1]))
+        .collect();
     let times: Vec<f32> = event_log.deaths.iter()
         .map(|e| e.time)
         .collect();
@@ -71,6 +143,121 @@ fn events_to_deaths_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
     DataFrame::new(vec![
         Series::new("entity".into(), entities),
         Series::new("age".into(), ages),
+        Series::new("cause".into(), causes),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert stillbirth events to polars DataFrame
+fn events_to_stillbirths_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let mother_entities: Vec<u64> = event_log.stillbirths.iter()
+        .map(|e| e.mother_entity.to_bits())
+        .collect();
+    let father_entities: Vec<Option<u64>> = event_log.stillbirths.iter()
+        .map(|e| e.father_entity.map(|f| f.to_bits()))
+        .collect();
+    let xs: Vec<Option<f32>> = event_log.stillbirths.iter()
+        .map(|e| e.location.map(|l| l[0]))
+        .collect();
+    let ys: Vec<Option<f32>> = event_log.stillbirths.iter()
+        .map(|e| e.location.map(|l| l[1]))
+        .collect();
+    let times: Vec<f32> = event_log.stillbirths.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("mother_entity".into(), mother_entities),
+        Series::new("father_entity".into(), father_entities),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert conception events to polars DataFrame
+fn events_to_conceptions_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let pregnancy_ids: Vec<u32> = event_log.conceptions.iter()
+        .map(|e| e.pregnancy_id)
+        .collect();
+    let mother_entities: Vec<u64> = event_log.conceptions.iter()
+        .map(|e| e.mother_entity.to_bits())
+        .collect();
+    let father_entities: Vec<u64> = event_log.conceptions.iter()
+        .map(|e| e.father_entity.to_bits())
+        .collect();
+    let ages: Vec<f32> = event_log.conceptions.iter()
+        .map(|e| e.age)
+        .collect();
+    let durations: Vec<f32> = event_log.conceptions.iter()
+        .map(|e| e.gestation_duration)
+        .collect();
+    let times: Vec<f32> = event_log.conceptions.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("pregnancy_id".into(), pregnancy_ids),
+        Series::new("mother_entity".into(), mother_entities),
+        Series::new("father_entity".into(), father_entities),
+        Series::new("age".into(), ages),
+        Series::new("gestation_duration".into(), durations),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert pregnancy end events to polars DataFrame
+fn events_to_pregnancy_ends_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let pregnancy_ids: Vec<Option<u32>> = event_log.pregnancy_ends.iter()
+        .map(|e| e.pregnancy_id)
+        .collect();
+    let mother_entities: Vec<u64> = event_log.pregnancy_ends.iter()
+        .map(|e| e.mother_entity.to_bits())
+        .collect();
+    let father_entities: Vec<Option<u64>> = event_log.pregnancy_ends.iter()
+        .map(|e| e.father_entity.map(|f| f.to_bits()))
+        .collect();
+    let outcomes: Vec<String> = event_log.pregnancy_ends.iter()
+        .map(|e| format!("{:?}", e.outcome))
+        .collect();
+    let gestational_ages: Vec<f32> = event_log.pregnancy_ends.iter()
+        .map(|e| e.gestational_age)
+        .collect();
+    let times: Vec<f32> = event_log.pregnancy_ends.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("pregnancy_id".into(), pregnancy_ids),
+        Series::new("mother_entity".into(), mother_entities),
+        Series::new("father_entity".into(), father_entities),
+        Series::new("outcome".into(), outcomes),
+        Series::new("gestational_age".into(), gestational_ages),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert completed fertility records to polars DataFrame
+fn events_to_completed_fertility_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let entities: Vec<u64> = event_log.completed_fertility.iter()
+        .map(|e| e.entity.to_bits())
+        .collect();
+    let birth_times: Vec<f32> = event_log.completed_fertility.iter()
+        .map(|e| e.birth_time)
+        .collect();
+    let parities: Vec<u32> = event_log.completed_fertility.iter()
+        .map(|e| e.parity as u32)
+        .collect();
+    let times: Vec<f32> = event_log.completed_fertility.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("entity".into(), entities),
+        Series::new("birth_time".into(), birth_times),
+        Series::new("parity".into(), parities),
         Series::new("time".into(), times),
     ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
 }
@@ -86,10 +273,49 @@ fn events_to_partnerships_dataframe(event_log: &EventLog) -> PyResult<DataFrame>
     let relationship_entities: Vec<u64> = event_log.partnerships.iter()
         .map(|e| e.relationship_entity.to_bits())
         .collect();
+    let union_types: Vec<String> = event_log.partnerships.iter()
+        .map(|e| format!("{:?}", e.union_type))
+        .collect();
+    let xs: Vec<Option<f32>> = event_log.partnerships.iter()
+        .map(|e| e.location.map(|l| l[0]))
+        .collect();
+    let ys: Vec<Option<f32>> = event_log.partnerships.iter()
+        .map(|e| e.location.map(|l| l[1]))
+        .collect();
+    let distances: Vec<Option<f32>> = event_log.partnerships.iter()
+        .map(|e| e.distance)
+        .collect();
     let times: Vec<f32> = event_log.partnerships.iter()
         .map(|e| e.time)
         .collect();
 
+    DataFrame::new(vec![
+        Series::new("individual1".into(), individual1s),
+        Series::new("individual2".into(), individual2s),
+        Series::new("relationship_entity".into(), relationship_entities),
+        Series::new("union_type".into(), union_types),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
+        Series::new("distance".into(), distances),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert marriage events to polars DataFrame
+fn events_to_marriages_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let individual1s: Vec<u64> = event_log.marriages.iter()
+        .map(|e| e.individual1.to_bits())
+        .collect();
+    let individual2s: Vec<u64> = event_log.marriages.iter()
+        .map(|e| e.individual2.to_bits())
+        .collect();
+    let relationship_entities: Vec<u64> = event_log.marriages.iter()
+        .map(|e| e.relationship_entity.to_bits())
+        .collect();
+    let times: Vec<f32> = event_log.marriages.iter()
+        .map(|e| e.time)
+        .collect();
+
     DataFrame::new(vec![
         Series::new("individual1".into(), individual1s),
         Series::new("individual2".into(), individual2s),
@@ -100,23 +326,35 @@ fn events_to_partnerships_dataframe(event_log: &EventLog) -> PyResult<DataFrame>
 
 /// Convert breakup events to polars DataFrame
 fn events_to_breakups_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
-    let male_entities: Vec<u64> = event_log.breakups.iter()
-        .map(|e| e.male_entity.to_bits())
+    let partner1s: Vec<u64> = event_log.breakups.iter()
+        .map(|e| e.partner1.to_bits())
         .collect();
-    let female_entities: Vec<u64> = event_log.breakups.iter()
-        .map(|e| e._female_entity.to_bits())
+    let partner2s: Vec<u64> = event_log.breakups.iter()
+        .map(|e| e.partner2.to_bits())
         .collect();
     let relationship_entities: Vec<u64> = event_log.breakups.iter()
         .map(|e| e.relationship_entity.to_bits())
         .collect();
+    let union_types: Vec<String> = event_log.breakups.iter()
+        .map(|e| format!("{:?}", e.union_type))
+        .collect();
+    let xs: Vec<Option<f32>> = event_log.breakups.iter()
+        .map(|e| e.location.map(|l| l[0]))
+        .collect();
+    let ys: Vec<Option<f32>> = event_log.breakups.iter()
+        .map(|e| e.location.map(|l| l[1]))
+        .collect();
     let times: Vec<f32> = event_log.breakups.iter()
         .map(|e| e.time)
         .collect();
 
     DataFrame::new(vec![
-        Series::new("male_entity".into(), male_entities),
-        Series::new("female_entity".into(), female_entities),
+        Series::new("partner1".into(), partner1s),
+        Series::new("partner2".into(), partner2s),
         Series::new("relationship_entity".into(), relationship_entities),
+        Series::new("union_type".into(), union_types),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
         Series::new("time".into(), times),
     ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
 }
@@ -144,13 +382,465 @@ fn events_to_widowings_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
     ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
 }
 
+/// Convert contact events to polars DataFrame
+fn events_to_contacts_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let individual1s: Vec<u64> = event_log.contacts.iter()
+        .map(|e| e.individual1.to_bits())
+        .collect();
+    let individual2s: Vec<u64> = event_log.contacts.iter()
+        .map(|e| e.individual2.to_bits())
+        .collect();
+    let relationship_entities: Vec<u64> = event_log.contacts.iter()
+        .map(|e| e.relationship_entity.to_bits())
+        .collect();
+    let union_types: Vec<String> = event_log.contacts.iter()
+        .map(|e| format!("{:?}", e.union_type))
+        .collect();
+    let times: Vec<f32> = event_log.contacts.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("individual1".into(), individual1s),
+        Series::new("individual2".into(), individual2s),
+        Series::new("relationship_entity".into(), relationship_entities),
+        Series::new("union_type".into(), union_types),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert orphanhood events to polars DataFrame
+fn events_to_orphanings_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let child_entities: Vec<u64> = event_log.orphanings.iter()
+        .map(|e| e.child_entity.to_bits())
+        .collect();
+    let deceased_parents: Vec<u64> = event_log.orphanings.iter()
+        .map(|e| e.deceased_parent.to_bits())
+        .collect();
+    let kinds: Vec<String> = event_log.orphanings.iter()
+        .map(|e| format!("{:?}", e.kind))
+        .collect();
+    let child_ages: Vec<f32> = event_log.orphanings.iter()
+        .map(|e| e.child_age)
+        .collect();
+    let times: Vec<f32> = event_log.orphanings.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("child_entity".into(), child_entities),
+        Series::new("deceased_parent".into(), deceased_parents),
+        Series::new("kind".into(), kinds),
+        Series::new("child_age".into(), child_ages),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert custody events to polars DataFrame
+fn events_to_custody_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let child_entities: Vec<u64> = event_log.custody.iter()
+        .map(|e| e.child_entity.to_bits())
+        .collect();
+    let custodians: Vec<u64> = event_log.custody.iter()
+        .map(|e| e.custodian.to_bits())
+        .collect();
+    let relationship_entities: Vec<u64> = event_log.custody.iter()
+        .map(|e| e.relationship_entity.to_bits())
+        .collect();
+    let times: Vec<f32> = event_log.custody.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("child_entity".into(), child_entities),
+        Series::new("custodian".into(), custodians),
+        Series::new("relationship_entity".into(), relationship_entities),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert migration events to polars DataFrame
+fn events_to_migrations_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let entities: Vec<u64> = event_log.migrations.iter()
+        .map(|e| e.entity.to_bits())
+        .collect();
+    let households: Vec<Option<u64>> = event_log.migrations.iter()
+        .map(|e| e.household.map(|h| h.to_bits()))
+        .collect();
+    let from_patches: Vec<u32> = event_log.migrations.iter()
+        .map(|e| e.from_patch as u32)
+        .collect();
+    let to_patches: Vec<u32> = event_log.migrations.iter()
+        .map(|e| e.to_patch as u32)
+        .collect();
+    let xs: Vec<f32> = event_log.migrations.iter()
+        .map(|e| e.location[0])
+        .collect();
+    let ys: Vec<f32> = event_log.migrations.iter()
+        .map(|e| e.location[1])
+        .collect();
+    let times: Vec<f32> = event_log.migrations.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("entity".into(), entities),
+        Series::new("household".into(), households),
+        Series::new("from_patch".into(), from_patches),
+        Series::new("to_patch".into(), to_patches),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert emigration events to polars DataFrame
+fn events_to_emigrations_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let entities: Vec<u64> = event_log.emigrations.iter()
+        .map(|e| e.entity.to_bits())
+        .collect();
+    let ages: Vec<f32> = event_log.emigrations.iter()
+        .map(|e| e.age)
+        .collect();
+    let sexes: Vec<String> = event_log.emigrations.iter()
+        .map(|e| format!("{:?}", e.sex))
+        .collect();
+    let xs: Vec<Option<f32>> = event_log.emigrations.iter()
+        .map(|e| e.location.map(|l| l[0]))
+        .collect();
+    let ys: Vec<Option<f32>> = event_log.emigrations.iter()
+        .map(|e| e.location.map(|l| l[1]))
+        .collect();
+    let patches: Vec<Option<u32>> = event_log.emigrations.iter()
+        .map(|e| e.patch.map(|p| p as u32))
+        .collect();
+    let times: Vec<f32> = event_log.emigrations.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("entity".into(), entities),
+        Series::new("age".into(), ages),
+        Series::new("sex".into(), sexes),
+        Series::new("x".into(), xs),
+        Series::new("y".into(), ys),
+        Series::new("patch".into(), patches),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert scenario parameter change events to polars DataFrame
+fn events_to_parameter_changes_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let parameters: Vec<String> = event_log.parameter_changes.iter()
+        .map(|e| e.parameter.clone())
+        .collect();
+    let values: Vec<f32> = event_log.parameter_changes.iter()
+        .map(|e| e.value)
+        .collect();
+    let times: Vec<f32> = event_log.parameter_changes.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("parameter".into(), parameters),
+        Series::new("value".into(), values),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert intervention enrolment events to polars DataFrame
+fn events_to_enrolments_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let entities: Vec<u64> = event_log.enrolments.iter()
+        .map(|e| e.entity.to_bits())
+        .collect();
+    let interventions: Vec<String> = event_log.enrolments.iter()
+        .map(|e| e.intervention.clone())
+        .collect();
+    let ages: Vec<f32> = event_log.enrolments.iter()
+        .map(|e| e.age)
+        .collect();
+    let times: Vec<f32> = event_log.enrolments.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("entity".into(), entities),
+        Series::new("intervention".into(), interventions),
+        Series::new("age".into(), ages),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert infection events to polars DataFrame
+fn events_to_infections_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let entities: Vec<u64> = event_log.infections.iter()
+        .map(|e| e.entity.to_bits())
+        .collect();
+    let sources: Vec<Option<u64>> = event_log.infections.iter()
+        .map(|e| e.source.map(|s| s.to_bits()))
+        .collect();
+    let routes: Vec<String> = event_log.infections.iter()
+        .map(|e| format!("{:?}", e.route))
+        .collect();
+    let ages: Vec<f32> = event_log.infections.iter()
+        .map(|e| e.age)
+        .collect();
+    let times: Vec<f32> = event_log.infections.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("entity".into(), entities),
+        Series::new("source".into(), sources),
+        Series::new("route".into(), routes),
+        Series::new("age".into(), ages),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert vaccination events to polars DataFrame
+fn events_to_vaccinations_dataframe(event_log: &EventLog) -> PyResult<DataFrame> {
+    let entities: Vec<u64> = event_log.vaccinations.iter()
+        .map(|e| e.entity.to_bits())
+        .collect();
+    let strategies: Vec<String> = event_log.vaccinations.iter()
+        .map(|e| format!("{:?}", e.strategy))
+        .collect();
+    let ages: Vec<f32> = event_log.vaccinations.iter()
+        .map(|e| e.age)
+        .collect();
+    let protected: Vec<bool> = event_log.vaccinations.iter()
+        .map(|e| e.protected)
+        .collect();
+    let times: Vec<f32> = event_log.vaccinations.iter()
+        .map(|e| e.time)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("entity".into(), entities),
+        Series::new("strategy".into(), strategies),
+        Series::new("age".into(), ages),
+        Series::new("protected".into(), protected),
+        Series::new("time".into(), times),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert household census records to polars DataFrame
+fn census_to_households_dataframe(census: &Census) -> PyResult<DataFrame> {
+    let times: Vec<f32> = census.households.iter()
+        .map(|r| r.time)
+        .collect();
+    let patches: Vec<Option<u32>> = census.households.iter()
+        .map(|r| r.patch.map(|p| p as u32))
+        .collect();
+    let households: Vec<u64> = census.households.iter()
+        .map(|r| r.household.to_bits())
+        .collect();
+    let sizes: Vec<u32> = census.households.iter()
+        .map(|r| r.size as u32)
+        .collect();
+    let children: Vec<u32> = census.households.iter()
+        .map(|r| r.children as u32)
+        .collect();
+    let adults: Vec<u32> = census.households.iter()
+        .map(|r| r.adults as u32)
+        .collect();
+    let elders: Vec<u32> = census.households.iter()
+        .map(|r| r.elders as u32)
+        .collect();
+    let couples: Vec<u32> = census.households.iter()
+        .map(|r| r.couples as u32)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("time".into(), times),
+        Series::new("patch".into(), patches),
+        Series::new("household".into(), households),
+        Series::new("size".into(), sizes),
+        Series::new("children".into(), children),
+        Series::new("adults".into(), adults),
+        Series::new("elders".into(), elders),
+        Series::new("couples".into(), couples),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert household size census records to polars DataFrame
+fn census_to_household_sizes_dataframe(census: &Census) -> PyResult<DataFrame> {
+    let times: Vec<f32> = census.household_sizes.iter()
+        .map(|r| r.time)
+        .collect();
+    let patches: Vec<Option<u32>> = census.household_sizes.iter()
+        .map(|r| r.patch.map(|p| p as u32))
+        .collect();
+    let sizes: Vec<u32> = census.household_sizes.iter()
+        .map(|r| r.size as u32)
+        .collect();
+    let counts: Vec<u32> = census.household_sizes.iter()
+        .map(|r| r.count as u32)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("time".into(), times),
+        Series::new("patch".into(), patches),
+        Series::new("size".into(), sizes),
+        Series::new("count".into(), counts),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert orphan census records to polars DataFrame
+fn census_to_orphans_dataframe(census: &Census) -> PyResult<DataFrame> {
+    let times: Vec<f32> = census.orphans.iter()
+        .map(|r| r.time)
+        .collect();
+    let patches: Vec<Option<u32>> = census.orphans.iter()
+        .map(|r| r.patch.map(|p| p as u32))
+        .collect();
+    let ages: Vec<u32> = census.orphans.iter()
+        .map(|r| r.age)
+        .collect();
+    let maternal: Vec<u32> = census.orphans.iter()
+        .map(|r| r.maternal as u32)
+        .collect();
+    let paternal: Vec<u32> = census.orphans.iter()
+        .map(|r| r.paternal as u32)
+        .collect();
+    let double: Vec<u32> = census.orphans.iter()
+        .map(|r| r.double as u32)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("time".into(), times),
+        Series::new("patch".into(), patches),
+        Series::new("age".into(), ages),
+        Series::new("maternal".into(), maternal),
+        Series::new("paternal".into(), paternal),
+        Series::new("double".into(), double),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Convert vaccination census records to polars DataFrame
+fn census_to_vaccinated_dataframe(census: &Census) -> PyResult<DataFrame> {
+    let times: Vec<f32> = census.vaccinations.iter()
+        .map(|r| r.time)
+        .collect();
+    let patches: Vec<Option<u32>> = census.vaccinations.iter()
+        .map(|r| r.patch.map(|p| p as u32))
+        .collect();
+    let ages: Vec<u32> = census.vaccinations.iter()
+        .map(|r| r.age)
+        .collect();
+    let population: Vec<u32> = census.vaccinations.iter()
+        .map(|r| r.population as u32)
+        .collect();
+    let vaccinated: Vec<u32> = census.vaccinations.iter()
+        .map(|r| r.vaccinated as u32)
+        .collect();
+
+    DataFrame::new(vec![
+        Series::new("time".into(), times),
+        Series::new("patch".into(), patches),
+        Series::new("age".into(), ages),
+        Series::new("population".into(), population),
+        Series::new("vaccinated".into(), vaccinated),
+    ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+}
+
+/// Kinship queries over the genealogy of a finished simulation
+///
+/// Individuals are identified by the u64 entity ids used in the event DataFrames.
+#[pyclass(name = "Genealogy")]
+struct PyGenealogy {
+    genealogy: Genealogy,
+}
+
+fn entity_from_id(id: u64) -> PyResult<Entity> {
+    Entity::try_from_bits(id)
+        .map_err(|_| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid entity id {}", id)))
+}
+
+fn entity_ids(entities: Vec<Entity>) -> Vec<u64> {
+    entities.into_iter().map(|e| e.to_bits()).collect()
+}
+
+#[pymethods]
+impl PyGenealogy {
+    fn parents(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.parents(entity_from_id(id)?)))
+    }
+
+    fn children(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.children(entity_from_id(id)?)))
+    }
+
+    fn siblings(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.siblings(entity_from_id(id)?)))
+    }
+
+    fn half_siblings(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.half_siblings(entity_from_id(id)?)))
+    }
+
+    fn grandparents(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.grandparents(entity_from_id(id)?)))
+    }
+
+    fn grandchildren(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.grandchildren(entity_from_id(id)?)))
+    }
+
+    fn cousins(&self, id: u64) -> PyResult<Vec<u64>> {
+        Ok(entity_ids(self.genealogy.cousins(entity_from_id(id)?)))
+    }
+
+    fn is_alive(&self, id: u64, time: f32) -> PyResult<bool> {
+        Ok(self.genealogy.is_alive(entity_from_id(id)?, time))
+    }
+
+    /// Dict of degree -> number of kin alive at `time`
+    #[pyo3(signature = (id, time, max_degree=4))]
+    fn living_kin_by_degree(&self, id: u64, time: f32, max_degree: u32) -> PyResult<std::collections::BTreeMap<u32, usize>> {
+        Ok(self.genealogy.living_kin_by_degree(entity_from_id(id)?, max_degree, time))
+    }
+
+    /// One row per individual: entity, mother, father, entry_time, death_time, exit_time (emigration)
+    fn to_dataframe(&self) -> PyResult<PyDataFrame> {
+        let mut records: Vec<(&Entity, &crate::kinship::KinRecord)> = self.genealogy.records().collect();
+        records.sort_by_key(|(e, _)| **e);
+
+        let entities: Vec<u64> = records.iter().map(|(e, _)| e.to_bits()).collect();
+        let mothers: Vec<Option<u64>> = records.iter().map(|(_, r)| r.mother.map(|m| m.to_bits())).collect();
+        let fathers: Vec<Option<u64>> = records.iter().map(|(_, r)| r.father.map(|f| f.to_bits())).collect();
+        let entry_times: Vec<f32> = records.iter().map(|(_, r)| r.entry_time).collect();
+        let death_times: Vec<Option<f32>> = records.iter().map(|(_, r)| r.death_time).collect();
+        let exit_times: Vec<Option<f32>> = records.iter().map(|(_, r)| r.exit_time).collect();
+
+        DataFrame::new(vec![
+            Series::new("entity".into(), entities),
+            Series::new("mother_entity".into(), mothers),
+            Series::new("father_entity".into(), fathers),
+            Series::new("entry_time".into(), entry_times),
+            Series::new("death_time".into(), death_times),
+            Series::new("exit_time".into(), exit_times),
+        ])
+        .map(PyDataFrame)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Polars error: {}", e)))
+    }
+}
+
 /// Run a demographic simulation with given parameters
 ///
 /// Parameters:
-/// - params: dict with simulation parameters (initial_population, sim_years, etc.)
+/// - params: dict with simulation parameters (initial_population, sim_years, etc.);
+///   "patches" takes a dict in the layout of the --patches JSON file,
+///   "scenario" a list of dicts in the layout of the --scenario JSON file,
+///   "family_planning" a list of (start, end, coverage, min_parity, conception_factor) interventions
 ///
 /// Returns:
-/// - dict with polars DataFrames: {"births": df, "deaths": df, "partnerships": df, "breakups": df, "widowings": df}
+/// - dict with polars DataFrames: {"births": df, "deaths": df, "stillbirths": df, "conceptions": df, "pregnancy_ends": df, "completed_fertility": df,
+///   "partnerships": df, "marriages": df, "breakups": df, "widowings": df, "contacts": df,
+///   "orphanings": df, "custody": df, "migrations": df, "emigrations": df, "parameter_changes": df, "enrolments": df, "infections": df, "vaccinations": df,
+///   "households": df, "household_sizes": df, "orphans": df, "vaccinated": df,
+///   "genealogy": Genealogy}
 ///
 /// Example:
 /// >>> import pybevy_demog
@@ -175,11 +865,18 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
         .and_then(|v| v.extract::<bool>().ok())
         .unwrap_or(false);
 
+    let export_network = params.get_item("export_network")?
+        .and_then(|v| v.extract::<bool>().ok())
+        .unwrap_or(false);
+
     // Build Args resource
     let args = Args {
         initial_population,
         sim_years,
         export_events,
+        export_network,
+        patches: None,
+        scenario: None,
     };
 
     // Build SimulationParameters with defaults, overriding from dict
@@ -194,6 +891,75 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
     if let Some(Ok(max_partner_seeking_age)) = params.get_item("max_partner_seeking_age")?.map(|v| v.extract::<f32>()) {
         sim_params.max_partner_seeking_age = max_partner_seeking_age;
     }
+    if let Some(Ok(homosexual_fraction)) = params.get_item("homosexual_fraction")?.map(|v| v.extract::<f32>()) {
+        sim_params.homosexual_fraction = homosexual_fraction;
+    }
+    if let Some(Ok(bisexual_fraction)) = params.get_item("bisexual_fraction")?.map(|v| v.extract::<f32>()) {
+        sim_params.bisexual_fraction = bisexual_fraction;
+    }
+    if let Some(Ok(background_mortality_rate)) = params.get_item("background_mortality_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.background_mortality_rate = background_mortality_rate;
+    }
+    if let Some(Ok(initial_sex_ratio)) = params.get_item("initial_sex_ratio")?.map(|v| v.extract::<f32>()) {
+        sim_params.initial_sex_ratio = initial_sex_ratio;
+    }
+    if let Some(Ok(sex_ratio_at_birth)) = params.get_item("sex_ratio_at_birth")?.map(|v| v.extract::<f32>()) {
+        sim_params.sex_ratio_at_birth = sex_ratio_at_birth;
+    }
+    if let Some(Ok(sex_ratio_by_birth_order)) = params.get_item("sex_ratio_by_birth_order")?.map(|v| v.extract::<Vec<f32>>()) {
+        sim_params.sex_ratio_by_birth_order = sex_ratio_by_birth_order;
+    }
+    if let Some(Ok(density_dependence)) = params.get_item("density_dependence")?.map(|v| v.extract::<bool>()) {
+        sim_params.density_dependence = density_dependence;
+    }
+    if let Some(Ok(carrying_capacity)) = params.get_item("carrying_capacity")?.map(|v| v.extract::<f32>()) {
+        sim_params.carrying_capacity = carrying_capacity;
+    }
+    if let Some(Ok(density_cell_size)) = params.get_item("density_cell_size")?.map(|v| v.extract::<f32>()) {
+        sim_params.density_cell_size = density_cell_size;
+    }
+    if let Some(Ok(density_fertility_strength)) = params.get_item("density_fertility_strength")?.map(|v| v.extract::<f32>()) {
+        sim_params.density_fertility_strength = density_fertility_strength;
+    }
+    if let Some(Ok(density_mortality_strength)) = params.get_item("density_mortality_strength")?.map(|v| v.extract::<f32>()) {
+        sim_params.density_mortality_strength = density_mortality_strength;
+    }
+    if let Some(Ok(grid_width)) = params.get_item("grid_width")?.map(|v| v.extract::<f32>()) {
+        sim_params.grid_width = grid_width;
+    }
+    if let Some(Ok(grid_height)) = params.get_item("grid_height")?.map(|v| v.extract::<f32>()) {
+        sim_params.grid_height = grid_height;
+    }
+    if let Some(Ok(spatial_matching)) = params.get_item("spatial_matching")?.map(|v| v.extract::<bool>()) {
+        sim_params.spatial_matching = spatial_matching;
+    }
+    if let Some(Ok(partner_search_radius)) = params.get_item("partner_search_radius")?.map(|v| v.extract::<f32>()) {
+        sim_params.partner_search_radius = partner_search_radius;
+    }
+    if let Some(Ok(leave_home_age)) = params.get_item("leave_home_age")?.map(|v| v.extract::<f32>()) {
+        sim_params.leave_home_age = leave_home_age;
+    }
+    if let Some(Ok(elder_coresidence_rate)) = params.get_item("elder_coresidence_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.elder_coresidence_rate = elder_coresidence_rate;
+    }
+    if let Some(Ok(custody_rule)) = params.get_item("custody_rule")?.map(|v| v.extract::<String>()) {
+        sim_params.custody_rule = match custody_rule.as_str() {
+            "mother" => CustodyRule::Mother,
+            "father" => CustodyRule::Father,
+            "random" => CustodyRule::Random,
+            other => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Unknown custody_rule '{}', expected 'mother', 'father' or 'random'", other))),
+        };
+    }
+    if let Some(Ok(orphan_age_limit)) = params.get_item("orphan_age_limit")?.map(|v| v.extract::<f32>()) {
+        sim_params.orphan_age_limit = orphan_age_limit;
+    }
+    if let Some(Ok(emigration_rates)) = params.get_item("emigration_rates")?.map(|v| v.extract::<Vec<(f32, f32, f32, f32)>>()) {
+        // (min_age, max_age, female_rate, male_rate) per age band
+        sim_params.emigration_rates = emigration_rates.into_iter()
+            .map(|(min_age, max_age, female, male)| EmigrationRate { min_age, max_age, female, male })
+            .collect();
+    }
     if let Some(Ok(min_conception_age)) = params.get_item("min_conception_age")?.map(|v| v.extract::<f32>()) {
         sim_params.min_conception_age = min_conception_age;
     }
@@ -203,12 +969,179 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
     if let Some(Ok(conception_rate)) = params.get_item("conception_rate")?.map(|v| v.extract::<f32>()) {
         sim_params.conception_rate = conception_rate;
     }
+    if let Some(Ok(cohabitation_fertility_ratio)) = params.get_item("cohabitation_fertility_ratio")?.map(|v| v.extract::<f32>()) {
+        sim_params.cohabitation_fertility_ratio = cohabitation_fertility_ratio;
+    }
     if let Some(Ok(gestation_duration)) = params.get_item("gestation_duration")?.map(|v| v.extract::<f32>()) {
         sim_params.gestation_duration = gestation_duration;
     }
+    if let Some(Ok(gestation_duration_sd)) = params.get_item("gestation_duration_sd")?.map(|v| v.extract::<f32>()) {
+        sim_params.gestation_duration_sd = gestation_duration_sd;
+    }
+    if let Some(Ok(postpartum_infecundity)) = params.get_item("postpartum_infecundity")?.map(|v| v.extract::<f32>()) {
+        sim_params.postpartum_infecundity = postpartum_infecundity;
+    }
+    if let Some(Ok(postpartum_infecundity_sd)) = params.get_item("postpartum_infecundity_sd")?.map(|v| v.extract::<f32>()) {
+        sim_params.postpartum_infecundity_sd = postpartum_infecundity_sd;
+    }
+    if let Some(Ok(breastfeeding_duration)) = params.get_item("breastfeeding_duration")?.map(|v| v.extract::<f32>()) {
+        sim_params.breastfeeding_duration = breastfeeding_duration;
+    }
+    if let Some(Ok(breastfeeding_infecundity_ratio)) = params.get_item("breastfeeding_infecundity_ratio")?.map(|v| v.extract::<f32>()) {
+        sim_params.breastfeeding_infecundity_ratio = breastfeeding_infecundity_ratio;
+    }
+    if let Some(Ok(parity_fertility_ratios)) = params.get_item("parity_fertility_ratios")?.map(|v| v.extract::<Vec<f32>>()) {
+        sim_params.parity_fertility_ratios = parity_fertility_ratios;
+    }
+    if let Some(Ok(desired_family_size)) = params.get_item("desired_family_size")?.map(|v| v.extract::<f32>()) {
+        sim_params.desired_family_size = desired_family_size;
+    }
+    if let Some(Ok(desired_family_size_sd)) = params.get_item("desired_family_size_sd")?.map(|v| v.extract::<f32>()) {
+        sim_params.desired_family_size_sd = desired_family_size_sd;
+    }
+    if let Some(Ok(pregnancy_loss_hazards)) = params.get_item("pregnancy_loss_hazards")?.map(|v| v.extract::<Vec<(f32, f32, f32)>>()) {
+        // (min_gestation, max_gestation, rate) per gestational age band
+        sim_params.pregnancy_loss_hazards = pregnancy_loss_hazards.into_iter()
+            .map(|(min_gestation, max_gestation, rate)| PregnancyLossHazard { min_gestation, max_gestation, rate })
+            .collect();
+    }
+    if let Some(Ok(multiple_birth_rates)) = params.get_item("multiple_birth_rates")?.map(|v| v.extract::<Vec<(f32, f32, f32, f32)>>()) {
+        // (min_age, max_age, twins, triplets) per maternal age band
+        sim_params.multiple_birth_rates = multiple_birth_rates.into_iter()
+            .map(|(min_age, max_age, twins, triplets)| MultipleBirthRate { min_age, max_age, twins, triplets })
+            .collect();
+    }
+    if let Some(Ok(maternal_mortality_prob)) = params.get_item("maternal_mortality_prob")?.map(|v| v.extract::<f32>()) {
+        sim_params.maternal_mortality_prob = maternal_mortality_prob;
+    }
+    if let Some(Ok(maternal_mortality_age_ratio)) = params.get_item("maternal_mortality_age_ratio")?.map(|v| v.extract::<f32>()) {
+        sim_params.maternal_mortality_age_ratio = maternal_mortality_age_ratio;
+    }
+    if let Some(Ok(maternal_mortality_parity_ratio)) = params.get_item("maternal_mortality_parity_ratio")?.map(|v| v.extract::<f32>()) {
+        sim_params.maternal_mortality_parity_ratio = maternal_mortality_parity_ratio;
+    }
+    if let Some(Ok(stillbirth_prob)) = params.get_item("stillbirth_prob")?.map(|v| v.extract::<f32>()) {
+        sim_params.stillbirth_prob = stillbirth_prob;
+    }
+    if let Some(Ok(neonatal_mortality_prob)) = params.get_item("neonatal_mortality_prob")?.map(|v| v.extract::<f32>()) {
+        sim_params.neonatal_mortality_prob = neonatal_mortality_prob;
+    }
+    if let Some(Ok(infant_mortality_rate)) = params.get_item("infant_mortality_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.infant_mortality_rate = infant_mortality_rate;
+    }
     if let Some(Ok(breakup_rate)) = params.get_item("breakup_rate")?.map(|v| v.extract::<f32>()) {
         sim_params.breakup_rate = breakup_rate;
     }
+    if let Some(Ok(marriage_rate)) = params.get_item("marriage_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.marriage_rate = marriage_rate;
+    }
+    if let Some(Ok(divorce_rate)) = params.get_item("divorce_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.divorce_rate = divorce_rate;
+    }
+    if let Some(Ok(breakup_reentry_delay)) = params.get_item("breakup_reentry_delay")?.map(|v| v.extract::<f32>()) {
+        sim_params.breakup_reentry_delay = breakup_reentry_delay;
+    }
+    if let Some(Ok(widowhood_reentry_delay)) = params.get_item("widowhood_reentry_delay")?.map(|v| v.extract::<f32>()) {
+        sim_params.widowhood_reentry_delay = widowhood_reentry_delay;
+    }
+    if let Some(Ok(reentry_delay_age_factor)) = params.get_item("reentry_delay_age_factor")?.map(|v| v.extract::<f32>()) {
+        sim_params.reentry_delay_age_factor = reentry_delay_age_factor;
+    }
+    if let Some(Ok(no_repartnering_prob)) = params.get_item("no_repartnering_prob")?.map(|v| v.extract::<f32>()) {
+        sim_params.no_repartnering_prob = no_repartnering_prob;
+    }
+    if let Some(Ok(concurrent_partnerships)) = params.get_item("concurrent_partnerships")?.map(|v| v.extract::<bool>()) {
+        sim_params.concurrent_partnerships = concurrent_partnerships;
+    }
+    if let Some(Ok(max_concurrent_partners)) = params.get_item("max_concurrent_partners")?.map(|v| v.extract::<usize>()) {
+        sim_params.max_concurrent_partners = max_concurrent_partners;
+    }
+    if let Some(Ok(casual_formation_rate)) = params.get_item("casual_formation_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.casual_formation_rate = casual_formation_rate;
+    }
+    if let Some(Ok(casual_dissolution_rate)) = params.get_item("casual_dissolution_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.casual_dissolution_rate = casual_dissolution_rate;
+    }
+    if let Some(Ok(steady_contact_rate)) = params.get_item("steady_contact_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.steady_contact_rate = steady_contact_rate;
+    }
+    if let Some(Ok(casual_contact_rate)) = params.get_item("casual_contact_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.casual_contact_rate = casual_contact_rate;
+    }
+    if let Some(Ok(initial_prevalence)) = params.get_item("initial_prevalence")?.map(|v| v.extract::<f32>()) {
+        sim_params.initial_prevalence = initial_prevalence;
+    }
+    if let Some(Ok(partner_transmission_rate)) = params.get_item("partner_transmission_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.partner_transmission_rate = partner_transmission_rate;
+    }
+    if let Some(Ok(transmission_per_act)) = params.get_item("transmission_per_act")?.map(|v| v.extract::<f32>()) {
+        sim_params.transmission_per_act = transmission_per_act;
+    }
+    if let Some(Ok(household_transmission_rate)) = params.get_item("household_transmission_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.household_transmission_rate = household_transmission_rate;
+    }
+    if let Some(Ok(recovery_rate)) = params.get_item("recovery_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.recovery_rate = recovery_rate;
+    }
+    if let Some(Ok(infection_immunity)) = params.get_item("infection_immunity")?.map(|v| v.extract::<bool>()) {
+        sim_params.infection_immunity = infection_immunity;
+    }
+    if let Some(Ok(infection_mortality_rate)) = params.get_item("infection_mortality_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.infection_mortality_rate = infection_mortality_rate;
+    }
+    if let Some(Ok(vertical_transmission_prob)) = params.get_item("vertical_transmission_prob")?.map(|v| v.extract::<f32>()) {
+        sim_params.vertical_transmission_prob = vertical_transmission_prob;
+    }
+    if let Some(Ok(maternal_antibody_prob)) = params.get_item("maternal_antibody_prob")?.map(|v| v.extract::<f32>()) {
+        sim_params.maternal_antibody_prob = maternal_antibody_prob;
+    }
+    if let Some(Ok(maternal_antibody_waning_rate)) = params.get_item("maternal_antibody_waning_rate")?.map(|v| v.extract::<f32>()) {
+        sim_params.maternal_antibody_waning_rate = maternal_antibody_waning_rate;
+    }
+    if let Some(Ok(routine_vaccinations)) = params.get_item("routine_vaccinations")?.map(|v| v.extract::<Vec<(f32, f32)>>()) {
+        // (age, coverage) per routine dose
+        sim_params.routine_vaccinations = routine_vaccinations.into_iter()
+            .map(|(age, coverage)| RoutineVaccination { age, coverage })
+            .collect();
+    }
+    if let Some(Ok(vaccination_campaigns)) = params.get_item("vaccination_campaigns")?.map(|v| v.extract::<Vec<(f32, f32, f32, f32)>>()) {
+        // (time, min_age, max_age, coverage) per campaign
+        sim_params.vaccination_campaigns = vaccination_campaigns.into_iter()
+            .map(|(time, min_age, max_age, coverage)| VaccinationCampaign { time, min_age, max_age, coverage })
+            .collect();
+    }
+    if let Some(Ok(vaccine_efficacy)) = params.get_item("vaccine_efficacy")?.map(|v| v.extract::<f32>()) {
+        sim_params.vaccine_efficacy = vaccine_efficacy;
+    }
+
+    sim_params.validate().map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        format!("Invalid parameters: {}", e)))?;
+
+    // Patches are given as a dict in the same layout as the --patches JSON file
+    let patches = match params.get_item("patches")? {
+        Some(v) => {
+            let json: String = py.import_bound("json")?.call_method1("dumps", (v,))?.extract()?;
+            Some(Patches::from_json(&json).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Invalid patches: {}", e)))?)
+        }
+        None => None,
+    };
+
+    // The scenario is given as a list of dicts in the same layout as the --scenario JSON file
+    let scenario = match params.get_item("scenario")? {
+        Some(v) => {
+            let json: String = py.import_bound("json")?.call_method1("dumps", (v,))?.extract()?;
+            Scenario::from_json(&json).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Invalid scenario: {}", e)))?
+        }
+        None => Scenario::default(),
+    };
+
+    // Family planning programs, the one built-in Intervention
+    let family_planning = match params.get_item("family_planning")? {
+        Some(v) => v.extract::<Vec<(f32, f32, f32, usize, f32)>>()?,
+        None => Vec::new(),
+    };
 
     // Create and run headless simulation with minimal logging
     let mut app = App::new();
@@ -217,10 +1150,14 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
     use std::sync::atomic::{AtomicBool, Ordering};
     static LOGGING_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
-    // Create capture resource to extract EventLog after simulation
+    // Create capture resource to extract EventLog and Census after simulation
     let capture = Arc::new(Mutex::new(None));
+    let capture_census = Arc::new(Mutex::new(None));
+    let capture_genealogy = Arc::new(Mutex::new(None));
     let capture_resource = EventLogCapture {
         captured: capture.clone(),
+        captured_census: capture_census.clone(),
+        captured_genealogy: capture_genealogy.clone(),
     };
 
     app
@@ -229,6 +1166,13 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
         .insert_resource(capture_resource)
         .add_plugins(MinimalPlugins);
 
+    if let Some(patches) = patches {
+        app.insert_resource(patches);
+    }
+    for (start, end, coverage, min_parity, conception_factor) in family_planning {
+        app.add_intervention(FamilyPlanning { start, end, coverage, min_parity, conception_factor });
+    }
+
     // Only add LogPlugin on first simulation run
     if !LOGGING_INITIALIZED.swap(true, Ordering::SeqCst) {
         app.add_plugins(bevy::log::LogPlugin {
@@ -242,8 +1186,21 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
         IndividualPlugin,
         PartnerPlugin,
         GestationPlugin,
+        HouseholdPlugin,
         ConfigPlugin,
-        EventLogPlugin
+        EventLogPlugin,
+        CensusPlugin,
+        OrphanhoodPlugin,
+        KinshipPlugin,
+        PatchPlugin,
+        EmigrationPlugin,
+        DensityPlugin,
+    ))
+    .add_plugins((
+        ScenarioPlugin(scenario),
+        InterventionPlugin,
+        InfectionPlugin,
+        VaccinationPlugin
     ))
     .add_systems(bevy::app::Last, capture_event_log_on_exit);
 
@@ -254,21 +1211,64 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
     let event_log = capture.lock().unwrap()
         .take()
         .expect("EventLog should have been captured on exit");
+    let census = capture_census.lock().unwrap()
+        .take()
+        .expect("Census should have been captured on exit");
+    let genealogy = capture_genealogy.lock().unwrap()
+        .take()
+        .expect("Genealogy should have been captured on exit");
 
     // Convert events to DataFrames
     let births_df = events_to_births_dataframe(&event_log)?;
     let deaths_df = events_to_deaths_dataframe(&event_log)?;
+    let stillbirths_df = events_to_stillbirths_dataframe(&event_log)?;
+    let conceptions_df = events_to_conceptions_dataframe(&event_log)?;
+    let pregnancy_ends_df = events_to_pregnancy_ends_dataframe(&event_log)?;
+    let completed_fertility_df = events_to_completed_fertility_dataframe(&event_log)?;
     let partnerships_df = events_to_partnerships_dataframe(&event_log)?;
+    let marriages_df = events_to_marriages_dataframe(&event_log)?;
     let breakups_df = events_to_breakups_dataframe(&event_log)?;
     let widowings_df = events_to_widowings_dataframe(&event_log)?;
+    let contacts_df = events_to_contacts_dataframe(&event_log)?;
+    let orphanings_df = events_to_orphanings_dataframe(&event_log)?;
+    let custody_df = events_to_custody_dataframe(&event_log)?;
+    let migrations_df = events_to_migrations_dataframe(&event_log)?;
+    let emigrations_df = events_to_emigrations_dataframe(&event_log)?;
+    let parameter_changes_df = events_to_parameter_changes_dataframe(&event_log)?;
+    let enrolments_df = events_to_enrolments_dataframe(&event_log)?;
+    let infections_df = events_to_infections_dataframe(&event_log)?;
+    let vaccinations_df = events_to_vaccinations_dataframe(&event_log)?;
+    let households_df = census_to_households_dataframe(&census)?;
+    let household_sizes_df = census_to_household_sizes_dataframe(&census)?;
+    let orphans_df = census_to_orphans_dataframe(&census)?;
+    let vaccinated_df = census_to_vaccinated_dataframe(&census)?;
 
     // Create Python dict with DataFrames
     let result = PyDict::new_bound(py);
     result.set_item("births", PyDataFrame(births_df).into_py(py))?;
     result.set_item("deaths", PyDataFrame(deaths_df).into_py(py))?;
+    result.set_item("stillbirths", PyDataFrame(stillbirths_df).into_py(py))?;
+    result.set_item("conceptions", PyDataFrame(conceptions_df).into_py(py))?;
+    result.set_item("pregnancy_ends", PyDataFrame(pregnancy_ends_df).into_py(py))?;
+    result.set_item("completed_fertility", PyDataFrame(completed_fertility_df).into_py(py))?;
     result.set_item("partnerships", PyDataFrame(partnerships_df).into_py(py))?;
+    result.set_item("marriages", PyDataFrame(marriages_df).into_py(py))?;
     result.set_item("breakups", PyDataFrame(breakups_df).into_py(py))?;
     result.set_item("widowings", PyDataFrame(widowings_df).into_py(py))?;
+    result.set_item("contacts", PyDataFrame(contacts_df).into_py(py))?;
+    result.set_item("orphanings", PyDataFrame(orphanings_df).into_py(py))?;
+    result.set_item("custody", PyDataFrame(custody_df).into_py(py))?;
+    result.set_item("migrations", PyDataFrame(migrations_df).into_py(py))?;
+    result.set_item("emigrations", PyDataFrame(emigrations_df).into_py(py))?;
+    result.set_item("parameter_changes", PyDataFrame(parameter_changes_df).into_py(py))?;
+    result.set_item("enrolments", PyDataFrame(enrolments_df).into_py(py))?;
+    result.set_item("infections", PyDataFrame(infections_df).into_py(py))?;
+    result.set_item("vaccinations", PyDataFrame(vaccinations_df).into_py(py))?;
+    result.set_item("households", PyDataFrame(households_df).into_py(py))?;
+    result.set_item("household_sizes", PyDataFrame(household_sizes_df).into_py(py))?;
+    result.set_item("orphans", PyDataFrame(orphans_df).into_py(py))?;
+    result.set_item("vaccinated", PyDataFrame(vaccinated_df).into_py(py))?;
+    result.set_item("genealogy", Py::new(py, PyGenealogy { genealogy })?)?;
 
     Ok(result.into())
 }
@@ -277,5 +1277,6 @@ fn run_simulation(py: Python, params: &Bound<'_, PyDict>) -> PyResult<Py<PyDict>
 #[pymodule]
 fn bevy_abm(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(run_simulation, m)?)?;
+    m.add_class::<PyGenealogy>()?;
     Ok(())
 }
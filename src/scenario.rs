@@ -0,0 +1,137 @@
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::patch::refresh_patch_parameters;
+use crate::config::SimulationParameters;
+
+// Emitted each time the scenario changes a parameter, including every step of a ramp
+#[derive(Event, Serialize, Clone)]
+pub struct ParameterChangeEvent {
+    pub parameter: String,
+    pub value: f32,
+    pub time: f32,
+}
+
+// Runs the given scenario, already loaded and validated by the caller
+pub struct ScenarioPlugin(pub Scenario);
+
+impl Plugin for ScenarioPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(self.0.clone())
+            .add_event::<ParameterChangeEvent>()
+
+        //-- SCENARIO
+        // Before the patch parameters are refreshed so patches see changes in the same frame
+        .add_systems(PreUpdate, apply_scenario
+            .before(refresh_patch_parameters)
+            .run_if(on_timer(Duration::from_secs_f32(SCENARIO_TIMESTEP))));
+    }
+}
+
+//-- SCENARIO
+const SCENARIO_TIMESTEP: f32 = 1.0/12.0;
+
+// ------ SCENARIO ------
+
+// A timed change to a numeric SimulationParameters field, times in simulated years
+#[derive(Deserialize, Clone, Debug)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum ScenarioAction {
+    // Set the parameter to `value` from `time` on
+    Set { time: f32, parameter: String, value: f32 },
+    // Move the parameter linearly from `from` at `start` to `to` at `end`
+    Ramp { start: f32, end: f32, parameter: String, from: f32, to: f32 },
+}
+
+impl ScenarioAction {
+    pub fn parameter(&self) -> &str {
+        match self {
+            ScenarioAction::Set { parameter, .. } => parameter,
+            ScenarioAction::Ramp { parameter, .. } => parameter,
+        }
+    }
+
+    // Value the parameter should hold at `time`, None before the action starts
+    pub fn value_at(&self, time: f32) -> Option<f32> {
+        match self {
+            ScenarioAction::Set { time: at, value, .. } => (time >= *at).then_some(*value),
+            ScenarioAction::Ramp { start, end, from, to, .. } => {
+                if time < *start {
+                    return None;
+                }
+                let fraction = if end > start { ((time - start) / (end - start)).min(1.0) } else { 1.0 };
+                Some(from + fraction * (to - from))
+            }
+        }
+    }
+}
+
+#[derive(Resource, Deserialize, Clone, Default, Debug)]
+#[serde(transparent)]
+pub struct Scenario {
+    pub actions: Vec<ScenarioAction>,
+    #[serde(skip)]
+    applied: Vec<Option<f32>>,  // last value set by each action
+}
+
+impl Scenario {
+    pub fn new(actions: Vec<ScenarioAction>) -> Self {
+        Self { actions, applied: Vec::new() }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let scenario: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        Self::from_json(&fs::read_to_string(path).map_err(|e| e.to_string())?)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let mut params = SimulationParameters::default();
+        for action in self.actions.iter() {
+            params.set_by_name(action.parameter(), 0.0)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn apply_scenario(
+    mut scenario: ResMut<Scenario>,
+    mut params: ResMut<SimulationParameters>,
+    mut change_events: EventWriter<ParameterChangeEvent>,
+    time: Res<Time>
+) {
+    let now = time.elapsed_seconds();
+    let scenario = scenario.as_mut();
+    scenario.applied.resize(scenario.actions.len(), None);
+
+    for (action, applied) in scenario.actions.iter().zip(scenario.applied.iter_mut()) {
+        let Some(value) = action.value_at(now) else {
+            continue;
+        };
+        if *applied == Some(value) {
+            continue;
+        }
+        *applied = Some(value);
+
+        if let Err(e) = params.set_by_name(action.parameter(), value) {
+            warn!("Scenario: {}", e);
+            continue;
+        }
+        debug!("Scenario set {} to {} at time {:.2}", action.parameter(), value, now);
+        change_events.send(ParameterChangeEvent {
+            parameter: action.parameter().to_string(),
+            value,
+            time: now,
+        });
+    }
+}
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use std::time::Duration;
+
+use rand::prelude::random;
+
+use serde::Serialize;
+
+use crate::individual::Demog;
+use crate::infection::DiseaseState;
+use crate::patch::{InPatch, LocalParameters};
+use crate::config::SimulationParameters;
+
+#[derive(Event, Serialize, Clone)]
+pub struct VaccinationEvent {
+    pub entity: Entity,
+    pub strategy: VaccinationStrategy,
+    pub age: f32,
+    pub protected: bool,  // the vaccine took and the individual was susceptible
+    pub time: f32,
+}
+
+pub struct VaccinationPlugin;
+
+impl Plugin for VaccinationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<VaccinationEvent>()
+
+        //-- VACCINATION
+        .add_systems(Update, (
+            routine_vaccination,
+            campaign_vaccination,
+        ).chain().run_if(on_timer(Duration::from_secs_f32(VACCINATION_TIMESTEP))));
+    }
+}
+
+//-- VACCINATION
+const VACCINATION_TIMESTEP: f32 = 1.0/12.0;
+
+// ------ VACCINATION ------
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub enum VaccinationStrategy {
+    Routine,
+    Campaign,
+}
+
+// Offered once to everyone reaching `age`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RoutineVaccination {
+    pub age: f32,
+    pub coverage: f32,
+}
+
+// Offered once at `time` to everyone aged [min_age, max_age)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VaccinationCampaign {
+    pub time: f32,
+    pub min_age: f32,
+    pub max_age: f32,
+    pub coverage: f32,
+}
+
+#[derive(Component)]
+pub struct Vaccinated;
+
+// Indices into routine_vaccinations already offered, whether accepted or not
+#[derive(Component, Clone, Default, Debug)]
+pub struct RoutineOffers(pub Vec<usize>);
+
+// Susceptible individuals become immune with probability vaccine_efficacy
+fn vaccinate(
+    e: Entity,
+    state_opt: Option<Mut<DiseaseState>>,
+    strategy: VaccinationStrategy,
+    age: f32,
+    params: &SimulationParameters,
+    time: f32
+) -> VaccinationEvent {
+    let mut protected = false;
+    if let Some(mut state) = state_opt {
+        if *state == DiseaseState::Susceptible && random::<f32>() < params.vaccine_efficacy {
+            *state = DiseaseState::Recovered;
+            protected = true;
+        }
+    }
+    debug!("{:?} vaccinated ({:?}) at age {:.1}", e, strategy, age);
+    VaccinationEvent {
+        entity: e,
+        strategy,
+        age,
+        protected,
+        time,
+    }
+}
+
+// A child who may be due a routine dose, with the doses already offered
+type VaccinationRecord = (Entity, &'static Demog, Option<&'static mut DiseaseState>, Option<&'static RoutineOffers>, Option<&'static InPatch>);
+
+pub fn routine_vaccination(
+    mut commands: Commands,
+    mut query: Query<VaccinationRecord>,
+    params: LocalParameters,
+    mut vaccination_events: EventWriter<VaccinationEvent>,
+    time: Res<Time>
+) {
+    for (e, demog, mut state_opt, offers_opt, patch_opt) in query.iter_mut() {
+        let params = params.get(patch_opt);
+        // Copied only once a dose is offered, most individuals are not due one
+        let mut updated: Option<RoutineOffers> = None;
+        for (i, dose) in params.routine_vaccinations.iter().enumerate() {
+            // Only up to a year past the target age, so older founders are not caught up
+            let already_offered = updated.as_ref().or(offers_opt).is_some_and(|offers| offers.0.contains(&i));
+            if demog.age < dose.age || demog.age >= dose.age + 1.0 || already_offered {
+                continue;
+            }
+            updated.get_or_insert_with(|| offers_opt.cloned().unwrap_or_default()).0.push(i);
+            if random::<f32>() < dose.coverage {
+                let state = state_opt.as_mut().map(|s| s.reborrow());
+                commands.entity(e).insert(Vaccinated);
+                vaccination_events.send(vaccinate(e, state, VaccinationStrategy::Routine, demog.age, params, time.elapsed_seconds()));
+            }
+        }
+        if let Some(offers) = updated {
+            commands.entity(e).insert(offers);
+        }
+    }
+}
+
+pub fn campaign_vaccination(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Demog, Option<&mut DiseaseState>, Option<&InPatch>)>,
+    params: LocalParameters,
+    mut vaccination_events: EventWriter<VaccinationEvent>,
+    mut done: Local<Vec<bool>>,
+    time: Res<Time>
+) {
+    let now = time.elapsed_seconds();
+    done.resize(params.global().vaccination_campaigns.len(), false);
+
+    for (i, campaign) in params.global().vaccination_campaigns.iter().enumerate() {
+        if done[i] || now < campaign.time {
+            continue;
+        }
+        done[i] = true;
+        info!("Vaccination campaign for ages {}-{} at time {:.2}", campaign.min_age, campaign.max_age, now);
+
+        for (e, demog, state_opt, patch_opt) in query.iter_mut() {
+            if demog.age < campaign.min_age || demog.age >= campaign.max_age || random::<f32>() >= campaign.coverage {
+                continue;
+            }
+            let params = params.get(patch_opt);
+            commands.entity(e).insert(Vaccinated);
+            vaccination_events.send(vaccinate(e, state_opt, VaccinationStrategy::Campaign, demog.age, params, now));
+        }
+    }
+}
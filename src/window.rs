@@ -6,17 +6,11 @@ use bevy_egui::{egui, EguiContexts, EguiPlugin};
 use rand::prelude::random;
 use std::fmt::Formatter;
 
-use crate::individual::{
-    Individual, Demog, Adult, Elder, Sex, spawn_individual, BirthEvent
+use bevy_abm::individual::{
+    Individual, Demog, Adult, Elder, Sex, spawn_individual, BirthEvent, BirthOrigin
 };
-use crate::partner::{
-    Partner, PartnerSeeking, Relationship, Partners, BreakupEvent
-};
-use crate::gestation::Mother;
-use crate::config::SimulationParameters;
-
-pub const GRID_WIDTH: u32 = 15;
-pub const GRID_HEIGHT: u32 = 15;
+use bevy_abm::location::Location;
+use bevy_abm::config::{SimulationParameters, CustodyRule};
 
 pub const WINDOW_PIXEL_WIDTH: f32 = 800.0;
 pub const WINDOW_PIXEL_HEIGHT: f32 = 800.0;
@@ -30,7 +24,6 @@ const FEMALE_COLOR: Color = Color::rgb(0.5, 0.2, 0.4);
 const MIN_SPRITE_SIZE: f32 = 0.05;
 const MAX_SPRITE_SIZE: f32 = 0.3;
 const MOVE_VELOCITY: f32 = 5.0;
-const PARTNER_DESTINATION_RANDOM_SCALE: f32 = 5.0;
 
 pub struct DisplayPlugin;
 
@@ -48,8 +41,7 @@ impl Plugin for DisplayPlugin {
             update_child_size,
             assign_new_adult_color,
             assign_elder_color,
-            assign_pair_destination,
-            handle_breakup_movement,
+            follow_location,
             move_towards,
             simulation_controls_ui,
         ))
@@ -68,12 +60,14 @@ fn keyboard_input(
     mut commands: Commands,
     keys: Res<ButtonInput<KeyCode>>,
     params: Res<SimulationParameters>,
+    camera_query: Query<&Transform, With<Camera2d>>,
     mut birth_events: EventWriter<BirthEvent>,
     time: Res<Time>
 ) {
     if keys.just_pressed(KeyCode::Enter) {
-        // Return was pressed --> add a random person
-        spawn_individual(&mut commands, params.spawn_individual_age, None, &mut birth_events, &time);
+        // Return was pressed --> add a random person in view
+        let location = calculate_spawn_location(&camera_query, &params);
+        spawn_individual(&mut commands, params.spawn_individual_age, Sex::draw(params.initial_sex_ratio), location, BirthOrigin::default(), &mut birth_events, &time);
     }
 }
 
@@ -110,37 +104,27 @@ fn camera_controls(
 
 // ------ SPAWNING POSITION ------
 
-fn calculate_spawn_position(
-    mother_position: Option<&Position>,
-    camera_query: &Query<&Transform, With<Camera2d>>
-) -> Position {
-    // If there's a mother position, spawn near her
-    if let Some(mother_pos) = mother_position {
-        return position_near_parent(mother_pos);
-    }
-
+fn calculate_spawn_location(
+    camera_query: &Query<&Transform, With<Camera2d>>,
+    params: &SimulationParameters
+) -> Location {
     // Try to spawn in camera view if camera exists
     if let Ok(camera_transform) = camera_query.get_single() {
         let camera_pos = camera_transform.translation;
 
         // Convert camera world position to grid coordinates
         // This reverses the conversion done in position_translation
-        let grid_x = (camera_pos.x + (WINDOW_PIXEL_WIDTH / 2.0)) / (WINDOW_PIXEL_WIDTH / GRID_WIDTH as f32);
-        let grid_y = (camera_pos.y + (WINDOW_PIXEL_HEIGHT / 2.0)) / (WINDOW_PIXEL_HEIGHT / GRID_HEIGHT as f32);
+        let grid_x = (camera_pos.x + (WINDOW_PIXEL_WIDTH / 2.0)) / (WINDOW_PIXEL_WIDTH / params.grid_width);
+        let grid_y = (camera_pos.y + (WINDOW_PIXEL_HEIGHT / 2.0)) / (WINDOW_PIXEL_HEIGHT / params.grid_height);
 
-        // Spawn randomly within the currently visible grid area
-        let spawn_x = grid_x + (random::<f32>() - 0.5) * GRID_WIDTH as f32;
-        let spawn_y = grid_y + (random::<f32>() - 0.5) * GRID_HEIGHT as f32;
-
-        // Clamp to ensure we stay within valid grid bounds
-        let spawn_x = spawn_x.clamp(0.0, GRID_WIDTH as f32);
-        let spawn_y = spawn_y.clamp(0.0, GRID_HEIGHT as f32);
-
-        return Position(Vec2::new(spawn_x, spawn_y));
+        // Spawn randomly within the currently visible grid area, clamped to the grid bounds
+        let spawn_x = grid_x + (random::<f32>() - 0.5) * params.grid_width;
+        let spawn_y = grid_y + (random::<f32>() - 0.5) * params.grid_height;
+        return Location::clamped(Vec2::new(spawn_x, spawn_y), params);
     }
 
     // Fallback to random position anywhere on grid
-    Position::random_cell()
+    Location::random(params)
 }
 
 // ------ DISPLAY ------
@@ -150,12 +134,10 @@ pub struct MovingTowards(Position);
 
 pub fn display_new_individual(
     mut commands: Commands,
-    query: Query<(Entity, &Demog, Option<&Mother>), Added<Individual>>,
-    mother_query: Query<&Position>,
-    camera_query: Query<&Transform, With<Camera2d>>,
+    query: Query<(Entity, &Demog, Option<&Location>), Added<Individual>>,
     params: Res<SimulationParameters>
 ) {
-    for (e, demog, mother_opt) in query.iter() {
+    for (e, demog, location_opt) in query.iter() {
         let color = if demog.age < params.min_partner_seeking_age {
             CHILD_COLOR
         } else {
@@ -173,11 +155,9 @@ pub fn display_new_individual(
             })
             .insert(Size::square(size_for_age(demog.age, params.min_partner_seeking_age)));
 
-        // Calculate spawn position based on mother location and camera view
-        let mother_position = mother_opt
-            .and_then(|mother| mother_query.get(mother.0).ok());
-        let position = calculate_spawn_position(mother_position, &camera_query);
-        commands.entity(e).insert(position);
+        // Display position starts at the core location (newborns are already placed near their mother)
+        let location = location_opt.copied().unwrap_or_else(|| Location::random(&params));
+        commands.entity(e).insert(Position(location.0));
 
     }
 }
@@ -194,13 +174,6 @@ fn size_for_age(age: f32, min_partner_seeking_age: f32) -> f32 {
     return MIN_SPRITE_SIZE + (MAX_SPRITE_SIZE - MIN_SPRITE_SIZE) * age / min_partner_seeking_age;
 }
 
-fn position_near_parent(p: &Position) -> Position {
-    Position(p.0 + Vec2::new(
-        random::<f32>() - 0.5,
-        random::<f32>() - 0.5
-    ))
-}
-
 pub fn update_child_size(
     mut query: Query<(&Demog, &mut Size), Without<Adult>>,
     params: Res<SimulationParameters>
@@ -232,46 +205,14 @@ pub fn assign_elder_color(
     }
 }
 
-pub fn assign_pair_destination(
-    mut commands: Commands,
-    rel_query: Query<&Partners, Added<Relationship>>,
-    ind_query: Query<(&Individual, &Position), (Without<Partner>, With<PartnerSeeking>)>
-) {
-    for partners in rel_query.iter() {
-        if let Ok((_ind1, pos1)) = ind_query.get(partners.e1) {
-            if let Ok((_ind2, pos2)) = ind_query.get(partners.e2) {
-
-                let midpoint = pos1.midpoint(pos2);
-
-                let destination = Position(midpoint.0 + Vec2::new(
-                    PARTNER_DESTINATION_RANDOM_SCALE * (random::<f32>() - 0.5),
-                    PARTNER_DESTINATION_RANDOM_SCALE * (random::<f32>() - 0.5),
-                ));
-                
-                commands.entity(partners.e1).insert(MovingTowards(destination));
-                commands.entity(partners.e2).insert(MovingTowards(destination));
-            } 
-        }
-    }
-}
-
-pub fn handle_breakup_movement(
+// Animate the display position towards the core location when individuals move home
+pub fn follow_location(
     mut commands: Commands,
-    mut breakup_events: EventReader<BreakupEvent>,
-    position_query: Query<&Position>
+    query: Query<(Entity, &Location, &Position), Changed<Location>>
 ) {
-    for event in breakup_events.read() {
-        if let Ok(male_position) = position_query.get(event.male_entity) {
-            // Move male away from current position
-            let move_distance = GRID_WIDTH as f32 * 0.05; // 5% of grid width
-            let random_direction = Vec2::new(
-                random::<f32>() - 0.5,
-                random::<f32>() - 0.5
-            ).normalize();
-            let destination = Position(male_position.0 + random_direction * move_distance);
-
-            commands.entity(event.male_entity).insert(MovingTowards(destination));
-            debug!("Male {:?} moving away after breakup at time {:.1}", event.male_entity, event.time);
+    for (e, location, position) in query.iter() {
+        if position.0 != location.0 {
+            commands.entity(e).insert(MovingTowards(Position(location.0)));
         }
     }
 }
@@ -297,13 +238,6 @@ pub fn move_towards(
 pub struct Position(pub Vec2);
 
 impl Position {
-    pub fn random_cell() -> Self {
-        Self(Vec2::new(
-            random::<f32>() * GRID_WIDTH as f32,
-            random::<f32>() * GRID_HEIGHT as f32,
-        ))
-    }
-
     pub fn distance(&self, other: &Self) -> f32 {
         self.0.distance(other.0)
     }
@@ -313,9 +247,6 @@ impl Position {
         Self(direction)
     }
 
-    pub fn midpoint(&self, other: &Self) -> Self {
-        Self((self.0 + other.0) * 0.5)
-    }
 }
 
 impl std::fmt::Display for Position {
@@ -324,7 +255,11 @@ impl std::fmt::Display for Position {
     }
 }
 
-fn position_translation(window_query: Query<&Window>, mut q: Query<(&Position, &mut Transform)>) {
+fn position_translation(
+    window_query: Query<&Window>,
+    params: Res<SimulationParameters>,
+    mut q: Query<(&Position, &mut Transform)>
+) {
     fn convert(pos: f32, bound_window: f32, bound_game: f32) -> f32 {
         let tile_size = bound_window / bound_game;
         pos / bound_game * bound_window - (bound_window / 2.) + (tile_size / 2.)
@@ -332,8 +267,8 @@ fn position_translation(window_query: Query<&Window>, mut q: Query<(&Position, &
     if let Ok(window) = window_query.get_single() {
         for (pos, mut transform) in q.iter_mut() {
             transform.translation = Vec3::new(
-                convert(pos.0.x, window.resolution.width(), GRID_WIDTH as f32),
-                convert(pos.0.y, window.resolution.height(), GRID_HEIGHT as f32),
+                convert(pos.0.x, window.resolution.width(), params.grid_width),
+                convert(pos.0.y, window.resolution.height(), params.grid_height),
                 0.0,
             );
         }
@@ -358,12 +293,16 @@ impl Size {
     }
 }
 
-fn size_scaling(window_query: Query<&Window>, mut q: Query<(&Size, &mut Transform)>) {
+fn size_scaling(
+    window_query: Query<&Window>,
+    params: Res<SimulationParameters>,
+    mut q: Query<(&Size, &mut Transform)>
+) {
     if let Ok(window) = window_query.get_single() {
         for (sprite_size, mut transform) in q.iter_mut() {
             transform.scale = Vec3::new(
-                sprite_size.width / GRID_WIDTH as f32 * window.resolution.width(),
-                sprite_size.height / GRID_HEIGHT as f32 * window.resolution.height(),
+                sprite_size.width / params.grid_width * window.resolution.width(),
+                sprite_size.height / params.grid_height * window.resolution.height(),
                 1.0,
             );
         }
@@ -457,10 +396,36 @@ fn simulation_controls_ui(
             ui.separator();
 
             // Breakup Rate slider
-            ui.label("Breakup Rate");
+            ui.label("Breakup Rate (cohabitation)");
             let response = ui.add(egui::Slider::new(&mut params.breakup_rate, 0.0..=1.0).text("per year"));
             if response.changed() {
                 info!("Breakup rate changed to: {}", params.breakup_rate);
             }
+
+            // Marriage Rate slider
+            ui.label("Marriage Rate");
+            let response = ui.add(egui::Slider::new(&mut params.marriage_rate, 0.0..=1.0).text("per year"));
+            if response.changed() {
+                info!("Marriage rate changed to: {}", params.marriage_rate);
+            }
+
+            // Divorce Rate slider
+            ui.label("Divorce Rate");
+            let response = ui.add(egui::Slider::new(&mut params.divorce_rate, 0.0..=1.0).text("per year"));
+            if response.changed() {
+                info!("Divorce rate changed to: {}", params.divorce_rate);
+            }
+
+            ui.separator();
+
+            // Custody rule after breakup
+            ui.label("Custody After Breakup");
+            ui.horizontal(|ui| {
+                for rule in [CustodyRule::Mother, CustodyRule::Father, CustodyRule::Random] {
+                    if ui.radio_value(&mut params.custody_rule, rule, format!("{:?}", rule)).changed() {
+                        info!("Custody rule changed to: {:?}", params.custody_rule);
+                    }
+                }
+            });
         });
 }
\ No newline at end of file
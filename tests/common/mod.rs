@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{BirthEvent, DeathEvent};
+use bevy_abm::gestation::{ConceptionEvent, StillbirthEvent, PregnancyEndEvent, CompletedFertilityEvent};
+use bevy_abm::infection::InfectionEvent;
+use bevy_abm::emigration::EmigrationEvent;
+use bevy_abm::patch::{PatchParameters, MigrationEvent};
+use bevy_abm::config::SimulationParameters;
+
+// World holding the parameters and the demographic events systems under test may send,
+// so a system writing a new event type only needs it added here
+pub fn setup_world(params: SimulationParameters) -> World {
+    let mut world = World::default();
+    world.insert_resource(params);
+    world.init_resource::<PatchParameters>();
+    world.init_resource::<Events<BirthEvent>>();
+    world.init_resource::<Events<DeathEvent>>();
+    world.init_resource::<Events<ConceptionEvent>>();
+    world.init_resource::<Events<StillbirthEvent>>();
+    world.init_resource::<Events<PregnancyEndEvent>>();
+    world.init_resource::<Events<CompletedFertilityEvent>>();
+    world.init_resource::<Events<InfectionEvent>>();
+    world.init_resource::<Events<EmigrationEvent>>();
+    world.init_resource::<Events<MigrationEvent>>();
+    world.init_resource::<Time>();
+    world
+}
@@ -0,0 +1,148 @@
+#[macro_use]
+extern crate approx;
+
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, BirthEvent, DeathEvent, DeathCause, Dying, update_age, remove_dead};
+use bevy_abm::gestation::{
+    RemainingGestation, Pregnancy, StillbirthEvent, PregnancyEndEvent, PregnancyOutcome,
+    update_gestation, maternal_mortality, maternal_death_prob, end_interrupted_pregnancies
+};
+use bevy_abm::emigration::{EmigrationRate, emigrate};
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+// Each world needs its own schedule, systems can't be shared between worlds
+fn run_delivery(world: &mut World) {
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_gestation, maternal_mortality, remove_dead).chain());
+    schedule.run(world);
+}
+
+fn spawn_mother(world: &mut World, age: f32) -> Entity {
+    world.spawn((
+        Individual,
+        Demog { age, sex: Sex::Female },
+        RemainingGestation(0.0)
+    )).id()
+}
+
+#[test]
+fn test_stillbirth_and_maternal_death() {
+
+    // Stillbirths spawn no child but are logged
+    let mut world = common::setup_world(SimulationParameters {
+        stillbirth_prob: 1.0,
+        ..Default::default()
+    });
+    let mother = spawn_mother(&mut world, 25.0);
+    run_delivery(&mut world);
+    assert_eq!(world.resource::<Events<BirthEvent>>().len(), 0, "No live birth");
+    let stillbirths: Vec<StillbirthEvent> = world.resource_mut::<Events<StillbirthEvent>>().drain().collect();
+    assert_eq!(stillbirths.len(), 1);
+    assert_eq!(stillbirths[0].mother_entity, mother);
+    assert!(world.get_entity(mother).is_some(), "Mother survives without maternal mortality");
+
+    // Mothers dying at delivery still leave a live newborn
+    let mut world = common::setup_world(SimulationParameters {
+        maternal_mortality_prob: 1.0,
+        ..Default::default()
+    });
+    let mother = spawn_mother(&mut world, 25.0);
+    run_delivery(&mut world);
+    assert!(world.get_entity(mother).is_none(), "Mother should have died in childbirth");
+    let births: Vec<BirthEvent> = world.resource_mut::<Events<BirthEvent>>().drain().collect();
+    assert_eq!(births.len(), 1);
+    assert!(world.get_entity(births[0].child_entity).is_some());
+    let deaths: Vec<DeathEvent> = world.resource_mut::<Events<DeathEvent>>().drain().collect();
+    assert_eq!(deaths.len(), 1);
+    assert_eq!(deaths[0].cause, DeathCause::Maternal);
+}
+
+#[test]
+fn test_maternal_risk_by_age_and_parity() {
+
+    let params = SimulationParameters {
+        maternal_mortality_prob: 0.01,
+        maternal_mortality_age_ratio: 2.0,
+        maternal_mortality_parity_ratio: 1.5,
+        ..Default::default()
+    };
+    assert_relative_eq!(maternal_death_prob(25.0, 1, &params), 0.01);
+    assert_relative_eq!(maternal_death_prob(18.0, 1, &params), 0.02);
+    assert_relative_eq!(maternal_death_prob(38.0, 2, &params), 0.02);
+    assert_relative_eq!(maternal_death_prob(25.0, 0, &params), 0.015);
+    assert_relative_eq!(maternal_death_prob(40.0, 5, &params), 0.03);
+}
+
+#[test]
+fn test_neonatal_and_infant_mortality() {
+
+    let mut world = common::setup_world(SimulationParameters {
+        neonatal_mortality_prob: 1.0,
+        infant_mortality_rate: 0.0,
+        ..Default::default()
+    });
+    let newborn = world.spawn((Individual, Demog { age: 0.0, sex: Sex::Male })).id();
+    let infant = world.spawn((Individual, Demog { age: 0.5, sex: Sex::Male })).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_age, remove_dead).chain());
+    schedule.run(&mut world);
+
+    assert!(world.get_entity(newborn).is_none(), "Newborn should die in the neonatal period");
+    assert!(world.get_entity(infant).is_some(), "Older infants are past the neonatal period");
+    let deaths: Vec<DeathEvent> = world.resource_mut::<Events<DeathEvent>>().drain().collect();
+    assert_eq!(deaths.len(), 1);
+    assert_eq!(deaths[0].cause, DeathCause::Infant);
+
+    // The infant hazard stops at age one
+    world.resource_mut::<SimulationParameters>().infant_mortality_rate = 10000.0;
+    let child = world.spawn((Individual, Demog { age: 2.0, sex: Sex::Male })).id();
+    schedule.run(&mut world);
+    assert!(world.get_entity(infant).is_none(), "Infant should die from the excess hazard");
+    assert!(world.get_entity(child).is_some());
+}
+
+#[test]
+fn test_pregnancy_ended_by_death_or_emigration() {
+
+    // Women in their forties all emigrate
+    let mut world = common::setup_world(SimulationParameters {
+        emigration_rates: vec![EmigrationRate { min_age: 40.0, max_age: 50.0, female: 1000.0, male: 0.0 }],
+        ..Default::default()
+    });
+    let father = world.spawn((Individual, Demog { age: 30.0, sex: Sex::Male })).id();
+    let dying = world.spawn((
+        Individual,
+        Demog { age: 30.0, sex: Sex::Female },
+        RemainingGestation(0.5),
+        Pregnancy { id: 1, father, duration: 0.75 },
+        Dying(DeathCause::Background)
+    )).id();
+    let emigrant = world.spawn((
+        Individual,
+        Demog { age: 45.0, sex: Sex::Female },
+        RemainingGestation(0.25),
+        Pregnancy { id: 2, father, duration: 0.75 }
+    )).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((emigrate, end_interrupted_pregnancies, remove_dead).chain_ignore_deferred());
+    schedule.run(&mut world);
+
+    assert!(world.get_entity(dying).is_none());
+    assert!(world.get_entity(emigrant).is_none());
+    let mut ends: Vec<PregnancyEndEvent> = world.resource_mut::<Events<PregnancyEndEvent>>().drain().collect();
+    ends.sort_by_key(|end| end.pregnancy_id);
+    assert_eq!(ends.len(), 2);
+    assert_eq!(ends[0].mother_entity, dying);
+    assert_eq!(ends[0].outcome, PregnancyOutcome::MaternalDeath);
+    assert_relative_eq!(ends[0].gestational_age, 0.25);
+    assert_eq!(ends[1].mother_entity, emigrant);
+    assert_eq!(ends[1].outcome, PregnancyOutcome::Censored);
+    assert_eq!(ends[1].father_entity, Some(father));
+    assert_relative_eq!(ends[1].gestational_age, 0.5);
+}
@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult};
+use bevy_abm::partner::{Partners, Couple, UnionType, Relationship,
+                        form_casual_partnerships, cleanup_casual_partnerships, prune_partner_lists, partnership_contacts,
+                        PartnerEvent, ContactEvent};
+use bevy_abm::location::Location;
+use bevy_abm::config::SimulationParameters;
+
+#[test]
+fn test_casual_partnerships_respect_cap_and_cleanup() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        concurrent_partnerships: true,
+        max_concurrent_partners: 2,
+        casual_formation_rate: 100.0, // Very high rate so everyone seeks every step
+        casual_contact_rate: 1000.0, // Contact every step
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Events<ContactEvent>>();
+    world.init_resource::<Time>();
+
+    // Create 3 females and 3 males - all adults
+    let mut individuals = Vec::new();
+    for i in 0..6 {
+        let sex = if i % 2 == 0 { Sex::Female } else { Sex::Male };
+        individuals.push(world.spawn((
+            Individual,
+            Adult,
+            Demog { age: 25.0, sex }
+        )).id());
+    }
+
+    let mut formation_schedule = Schedule::default();
+    formation_schedule.add_systems(form_casual_partnerships);
+    for _ in 0..20 {
+        formation_schedule.run(&mut world);
+    }
+
+    // Everyone should have casual partners without exceeding the concurrency cap
+    for &e in individuals.iter() {
+        let casual = world.get::<Partners>(e).expect("Should have Partners component");
+        assert!(!casual.0.is_empty(), "Should hold at least one casual relationship");
+        assert!(casual.0.len() <= params.max_concurrent_partners, "Should not exceed max_concurrent_partners");
+    }
+
+    let relationships: Vec<(Entity, Entity, Entity, UnionType)> = world.query::<(Entity, &Couple)>().iter(&world)
+        .map(|(e, p)| (e, p.e1, p.e2, p.union_type))
+        .collect();
+    assert!(relationships.len() > 3, "Individuals should hold concurrent relationships");
+    assert!(relationships.iter().all(|(_, _, _, t)| *t == UnionType::Casual), "All relationships should be casual");
+
+    // Each casual relationship pairs a female and a male, at most once
+    for (_, e1, e2, _) in relationships.iter() {
+        let sex1 = world.get::<Demog>(*e1).unwrap().sex;
+        let sex2 = world.get::<Demog>(*e2).unwrap().sex;
+        assert_ne!(sex1, sex2, "Heterosexual individuals should only pair with the opposite sex");
+        let duplicates = relationships.iter()
+            .filter(|(_, a, b, _)| (a == e1 && b == e2) || (a == e2 && b == e1))
+            .count();
+        assert_eq!(duplicates, 1, "The same pair should not form two relationships");
+    }
+
+    // Contacts are recorded against their relationship
+    let mut contact_schedule = Schedule::default();
+    contact_schedule.add_systems(partnership_contacts);
+    contact_schedule.run(&mut world);
+    let contact_events = world.resource::<Events<ContactEvent>>();
+    let mut reader = contact_events.get_reader();
+    let contact_rels: Vec<Entity> = reader.read(contact_events).map(|c| c.relationship_entity).collect();
+    assert_eq!(contact_rels.len(), relationships.len(), "Each relationship should have a contact");
+    assert!(relationships.iter().all(|(r, _, _, _)| contact_rels.contains(r)), "Contacts should reference existing relationships");
+
+    // Despawning an individual cleans up their casual relationships
+    let departed = individuals[0];
+    let departed_count = world.get::<Partners>(departed).unwrap().0.len();
+    world.despawn(departed);
+    let mut cleanup_schedule = Schedule::default();
+    cleanup_schedule.add_systems((cleanup_casual_partnerships, prune_partner_lists).chain());
+    cleanup_schedule.run(&mut world);
+
+    let remaining = world.query_filtered::<Entity, With<Relationship>>().iter(&world).count();
+    assert_eq!(remaining, relationships.len() - departed_count, "Departed individual's relationships should be removed");
+    let total_held: usize = world.query::<&Partners>().iter(&world).map(|c| c.0.len()).sum();
+    assert_eq!(total_held, 2 * remaining, "Survivors should only reference the remaining relationships");
+}
+
+#[test]
+fn test_casual_partners_within_radius() {
+
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        concurrent_partnerships: true,
+        max_concurrent_partners: 1,
+        casual_formation_rate: 10000.0,
+        spatial_matching: true,
+        partner_search_radius: 5.0,
+        ..Default::default()
+    });
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Time>();
+
+    // Two nearby couples-to-be and a distant pair
+    let spawn = |world: &mut World, sex: Sex, x: f32| world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 25.0, sex },
+        Location(Vec2::new(x, 10.0))
+    )).id();
+    let female1 = spawn(&mut world, Sex::Female, 10.0);
+    let male1 = spawn(&mut world, Sex::Male, 12.0);
+    let female2 = spawn(&mut world, Sex::Female, 50.0);
+    let male2 = spawn(&mut world, Sex::Male, 90.0);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(form_casual_partnerships);
+    schedule.run(&mut world);
+
+    let couples: Vec<(Entity, Entity)> = world.query::<&Couple>().iter(&world).map(|c| (c.e1, c.e2)).collect();
+    assert_eq!(couples, vec![(female1, male1)], "Only partners within the search radius should pair");
+    assert_eq!(world.get::<Partners>(male1).unwrap().0.len(), 1);
+    assert!(world.get::<Partners>(female2).is_none());
+    assert!(world.get::<Partners>(male2).is_none());
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, DeathEvent, DeathCause, update_age, remove_dead};
+use bevy_abm::infection::{DiseaseState, progress_infection};
+use bevy_abm::events::deaths_by_cause;
+use bevy_abm::config::SimulationParameters;
+
+#[test]
+fn test_death_causes() {
+
+    // One individual past death_age, the rest killed by a high background hazard
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        death_age: 70.0,
+        background_mortality_rate: 1000.0,
+        ..Default::default()
+    });
+    world.init_resource::<Events<DeathEvent>>();
+    world.init_resource::<Time>();
+    world.spawn((Individual, Demog { age: 75.0, sex: Sex::Male }));
+    for _ in 0..3 {
+        world.spawn((Individual, Demog { age: 30.0, sex: Sex::Female }));
+    }
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_age, remove_dead).chain());
+    schedule.run(&mut world);
+
+    let mut deaths: Vec<DeathEvent> = world.resource_mut::<Events<DeathEvent>>().drain().collect();
+    assert_eq!(deaths.len(), 4);
+    assert!(deaths.iter().filter(|d| d.age > 70.0).all(|d| d.cause == DeathCause::OldAge));
+
+    // User-defined causes are counted alongside the built-in ones
+    deaths.push(DeathEvent { entity: Entity::PLACEHOLDER, age: 40.0, cause: DeathCause::Custom("accident"), location: None, time: 0.0 });
+    let counts = deaths_by_cause(&deaths);
+    assert!(counts.contains(&(DeathCause::OldAge, 1)));
+    assert!(counts.contains(&(DeathCause::Background, 3)));
+    assert!(counts.contains(&(DeathCause::Custom("accident"), 1)));
+    assert_eq!(DeathCause::Custom("accident").name(), "accident");
+}
+
+#[test]
+fn test_one_death_per_individual() {
+
+    // Past death_age and dying of infection in the same step, with the two systems unordered
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        death_age: 70.0,
+        infection_mortality_rate: 1e6,
+        ..Default::default()
+    });
+    world.init_resource::<Events<DeathEvent>>();
+    world.init_resource::<Time>();
+    let e = world.spawn((Individual, Demog { age: 75.0, sex: Sex::Male }, DiseaseState::Infected)).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(((update_age, progress_infection), remove_dead).chain());
+    schedule.run(&mut world);
+
+    assert!(world.get_entity(e).is_none());
+    assert_eq!(world.resource::<Events<DeathEvent>>().len(), 1, "Only one death should be logged");
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, DeathEvent, update_age, remove_dead};
+use bevy_abm::location::Location;
+use bevy_abm::density::{PopulationDensity, update_population_density};
+use bevy_abm::patch::{Patches, PatchParameters, InPatch, refresh_patch_parameters};
+use bevy_abm::config::SimulationParameters;
+
+fn spawn_at(world: &mut World, x: f32, y: f32) -> Entity {
+    world.spawn((
+        Individual,
+        Demog { age: 30.0, sex: Sex::Female },
+        Location(Vec2::new(x, y))
+    )).id()
+}
+
+#[test]
+fn test_density_factors() {
+
+    // 20 individuals against a capacity of 10: 16 crowded in one corner cell, 4 in the opposite one
+    let mut world = World::default();
+    let params = SimulationParameters {
+        density_dependence: true,
+        carrying_capacity: 10.0,
+        grid_width: 10.0,
+        grid_height: 10.0,
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.init_resource::<PopulationDensity>();
+    for _ in 0..16 {
+        spawn_at(&mut world, 1.0, 1.0);
+    }
+    for _ in 0..4 {
+        spawn_at(&mut world, 9.0, 9.0);
+    }
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_population_density);
+    schedule.run(&mut world);
+
+    let density = world.resource::<PopulationDensity>().clone();
+    assert_eq!(density.total, 20);
+    assert!((density.fertility_factor(None, &params) - 2.0 / 3.0).abs() < 1e-6, "Twice the capacity lowers fertility by a third");
+    let empty = SimulationParameters { carrying_capacity: 1e6, ..params.clone() };
+    assert!(density.fertility_factor(None, &empty) <= 2.0, "Fertility stays bounded far below capacity");
+    assert!((density.mortality_factor(None, &params) - 2.0).abs() < 1e-6, "Twice the capacity doubles mortality");
+
+    // Per cell: each 5x5 cell holds a quarter of the capacity
+    world.insert_resource(SimulationParameters { density_cell_size: 5.0, ..params.clone() });
+    schedule.run(&mut world);
+    let density = world.resource::<PopulationDensity>().clone();
+    let cell_params = SimulationParameters { density_cell_size: 5.0, ..params.clone() };
+    let crowded = Location(Vec2::new(1.0, 1.0));
+    let sparse = Location(Vec2::new(9.0, 9.0));
+    assert!((density.relative_density(Some(&crowded), &cell_params) - 16.0 / 2.5).abs() < 1e-5);
+    assert!(density.fertility_factor(Some(&sparse), &cell_params) > density.fertility_factor(Some(&crowded), &cell_params),
+        "Fertility should be higher in the sparse cell");
+
+    let off = SimulationParameters { density_dependence: false, ..params };
+    assert_eq!(density.fertility_factor(Some(&crowded), &off), 1.0, "No scaling without density dependence");
+}
+
+#[test]
+fn test_density_across_patches() {
+
+    assert!(Patches::from_json(r#"{"patches": [{"name": "a", "center": [0, 0], "radius": 1, "overrides": {"carrying_capacity": 5}}]}"#).is_err(),
+        "The carrying capacity is shared by all patches and can't be overridden");
+    assert!(Patches::from_json(r#"{"patches": [{"name": "a", "center": [0, 0], "radius": 1, "overrides": {"density_mortality_strength": 2}}]}"#).is_err());
+
+    // 16 in a crowded west village and 4 in a sparse east one, against a capacity of 10 for the whole grid
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        density_dependence: true,
+        carrying_capacity: 10.0,
+        grid_width: 10.0,
+        grid_height: 10.0,
+        ..Default::default()
+    });
+    world.insert_resource(Patches::from_json(r#"{"patches": [
+        {"name": "west", "center": [1.0, 5.0], "radius": 1.0},
+        {"name": "east", "center": [9.0, 5.0], "radius": 1.0, "overrides": {"background_mortality_rate": 0.5}}
+    ]}"#).unwrap());
+    world.init_resource::<PatchParameters>();
+    world.init_resource::<PopulationDensity>();
+    for _ in 0..16 {
+        let e = spawn_at(&mut world, 1.0, 5.0);
+        world.entity_mut(e).insert(InPatch(0));
+    }
+    for _ in 0..4 {
+        let e = spawn_at(&mut world, 9.0, 5.0);
+        world.entity_mut(e).insert(InPatch(1));
+    }
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((refresh_patch_parameters, update_population_density));
+    schedule.run(&mut world);
+
+    // Both patches see the total population against the same capacity
+    let density = world.resource::<PopulationDensity>().clone();
+    for patch_params in world.resource::<PatchParameters>().0.iter() {
+        assert!((density.relative_density(None, patch_params) - 2.0).abs() < 1e-6);
+        assert!((density.mortality_factor(None, patch_params) - 2.0).abs() < 1e-6);
+    }
+}
+
+// Deaths in one step among 1000 individuals in a single cell, for a given carrying capacity
+fn deaths_at_capacity(carrying_capacity: f32) -> usize {
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        background_mortality_rate: 1.2,  // a monthly hazard of 0.1 at capacity
+        density_dependence: true,
+        carrying_capacity,
+        ..Default::default()
+    });
+    world.init_resource::<PopulationDensity>();
+    world.init_resource::<Events<DeathEvent>>();
+    world.init_resource::<Time>();
+    for _ in 0..1000 {
+        spawn_at(&mut world, 1.0, 1.0);
+    }
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_population_density, update_age, remove_dead).chain());
+    schedule.run(&mut world);
+
+    world.resource::<Events<DeathEvent>>().len()
+}
+
+#[test]
+fn test_background_mortality_scales_with_density() {
+
+    // At capacity about 95 of 1000 die; at four times capacity the hazard quadruples, about 330 die
+    let at_capacity = deaths_at_capacity(1000.0);
+    let crowded = deaths_at_capacity(250.0);
+    assert!(at_capacity > 50 && at_capacity < 150, "Unexpected deaths at capacity: {}", at_capacity);
+    assert!(crowded > 2 * at_capacity, "Crowding should raise mortality: {} vs {}", crowded, at_capacity);
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult};
+use bevy_abm::partner::{Partner, Couple, Relationship, UnionType, Married, ReentryDelay, WidowEvent, detect_widows};
+use bevy_abm::emigration::{EmigrationEvent, EmigrationRate, emigrate, emigration_rate};
+use bevy_abm::config::SimulationParameters;
+
+#[test]
+fn test_emigration_schedule() {
+
+    let schedule = [
+        EmigrationRate { min_age: 18.0, max_age: 30.0, female: 0.1, male: 0.2 },
+        EmigrationRate { min_age: 30.0, max_age: 50.0, female: 0.05, male: 0.05 },
+    ];
+    assert_eq!(emigration_rate(&schedule, 20.0, Sex::Male), 0.2);
+    assert_eq!(emigration_rate(&schedule, 20.0, Sex::Female), 0.1);
+    assert_eq!(emigration_rate(&schedule, 30.0, Sex::Female), 0.05);
+    assert_eq!(emigration_rate(&schedule, 10.0, Sex::Male), 0.0, "No emigration outside the age bands");
+}
+
+#[test]
+fn test_emigrant_leaves_partner_without_widowing() {
+
+    // Setup world with resources: every man emigrates, women stay
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        emigration_rates: vec![EmigrationRate { min_age: 0.0, max_age: 100.0, female: 0.0, male: 1000.0 }],
+        breakup_reentry_delay: 1.0,
+        ..Default::default()
+    });
+    world.init_resource::<Events<EmigrationEvent>>();
+    world.init_resource::<Events<WidowEvent>>();
+    world.init_resource::<Time>();
+
+    let wife = world.spawn((Individual, Adult, Demog { age: 30.0, sex: Sex::Female }, Married)).id();
+    let husband = world.spawn((Individual, Adult, Demog { age: 32.0, sex: Sex::Male }, Married)).id();
+    let relationship = world.spawn((Relationship, Couple { e1: wife, e2: husband, union_type: UnionType::Marriage })).id();
+    world.entity_mut(wife).insert(Partner(husband));
+    world.entity_mut(husband).insert(Partner(wife));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((emigrate, detect_widows).chain());
+    schedule.run(&mut world);
+
+    assert!(world.get_entity(husband).is_none(), "Emigrant should be removed from the population");
+    assert!(world.get_entity(relationship).is_none(), "Relationship should be dissolved");
+    assert!(world.get::<Partner>(wife).is_none());
+    assert!(world.get::<Married>(wife).is_none());
+    assert!(world.get::<ReentryDelay>(wife).is_some(), "Partner left behind re-enters the market as after a breakup");
+
+    let emigrations: Vec<EmigrationEvent> = world.resource_mut::<Events<EmigrationEvent>>().drain().collect();
+    assert_eq!(emigrations.len(), 1);
+    assert_eq!(emigrations[0].entity, husband);
+    assert!(world.resource::<Events<WidowEvent>>().is_empty(), "Emigration should not produce a widowing");
+}
@@ -5,7 +5,7 @@ use bevy::prelude::*;
 use bevy::ecs::event::Events;
 
 use bevy_abm::individual::{Individual, Demog, Sex, Adult, BirthEvent};
-use bevy_abm::gestation::{RemainingGestation, update_gestation, Mother};
+use bevy_abm::gestation::{RemainingGestation, StillbirthEvent, PregnancyEndEvent, update_gestation, Mother};
 use bevy_abm::config::SimulationParameters;
 
 #[test]
@@ -25,6 +25,8 @@ fn test_gestation_completes_after_correct_timesteps() {
     };
     world.insert_resource(params.clone());
     world.init_resource::<Events<BirthEvent>>();
+    world.init_resource::<Events<StillbirthEvent>>();
+    world.init_resource::<Events<PregnancyEndEvent>>();
     world.init_resource::<Time>();
 
     // Create pregnant female
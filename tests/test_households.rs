@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult};
+use bevy_abm::partner::{PartnerEvent, BreakupEvent, UnionType};
+use bevy_abm::gestation::Mother;
+use bevy_abm::household::{Household, HouseholdMember, LeftHome, CustodyEvent, join_household_at_birth,
+                          form_partner_households, split_households_on_breakup,
+                          leave_parental_home, dissolve_empty_households};
+use bevy_abm::config::SimulationParameters;
+
+fn household_of(world: &World, e: Entity) -> Entity {
+    world.get::<HouseholdMember>(e).expect("Individual should belong to a household").0
+}
+
+#[test]
+fn test_household_lifecycle() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        leave_home_age: 18.0,
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Events<BreakupEvent>>();
+    world.init_resource::<Events<CustodyEvent>>();
+    world.init_resource::<Time>();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((
+        join_household_at_birth,
+        form_partner_households,
+        split_households_on_breakup,
+        leave_parental_home,
+        dissolve_empty_households,
+    ).chain());
+
+    // A single mother with her child, and a single man
+    let mother = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 30.0, sex: Sex::Female }
+    )).id();
+    let man = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 32.0, sex: Sex::Male }
+    )).id();
+    schedule.run(&mut world);
+
+    let child = world.spawn((
+        Individual,
+        Demog { age: 0.0, sex: Sex::Male },
+        Mother(mother)
+    )).id();
+    schedule.run(&mut world);
+
+    assert_ne!(household_of(&world, mother), household_of(&world, man), "Unrelated adults should start in separate households");
+    assert_eq!(household_of(&world, child), household_of(&world, mother), "Newborn should join mother's household");
+
+    // Partnership: the couple moves in together, bringing the mother's child
+    let old_household = household_of(&world, mother);
+    world.send_event(PartnerEvent {
+        individual1: mother,
+        individual2: man,
+        relationship_entity: Entity::PLACEHOLDER,
+        union_type: UnionType::Cohabitation,
+        location: None,
+        distance: None,
+        time: 0.0,
+    });
+    schedule.run(&mut world);
+
+    let shared = household_of(&world, mother);
+    assert_ne!(shared, old_household, "Couple should form a new household");
+    assert_eq!(household_of(&world, man), shared, "Partners should share a household");
+    assert_eq!(household_of(&world, child), shared, "Dependent child should move with the mother");
+
+    // Breakup: the man moves out, the child stays with the mother
+    world.send_event(BreakupEvent {
+        partner1: mother,
+        partner2: man,
+        relationship_entity: Entity::PLACEHOLDER,
+        union_type: UnionType::Cohabitation,
+        location: None,
+        time: 1.0,
+    });
+    schedule.run(&mut world);
+
+    assert_eq!(household_of(&world, mother), shared, "Mother should keep the household");
+    assert_eq!(household_of(&world, child), shared, "Child should stay with the mother");
+    assert_ne!(household_of(&world, man), shared, "Man should move out after breakup");
+
+    // Leaving home: the grown-up child forms their own household
+    world.get_mut::<Demog>(child).unwrap().age = params.leave_home_age;
+    schedule.run(&mut world);
+
+    assert!(world.get::<LeftHome>(child).is_some(), "Child should have left home");
+    assert_ne!(household_of(&world, child), shared, "Child should live in a new household");
+
+    // Empty households are cleaned up: one per remaining member
+    schedule.run(&mut world);
+    let households = world.query_filtered::<Entity, With<Household>>().iter(&world).count();
+    assert_eq!(households, 3, "Mother, man and grown-up child should each have their own household");
+}
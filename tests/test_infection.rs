@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, BirthEvent, DeathEvent, DeathCause, MaternalTransfer, remove_dead};
+use bevy_abm::gestation::{RemainingGestation, update_gestation,
+                          MaternalTransferRules, MaternalTransferRule, Delivery};
+use bevy_abm::partner::{Couple, Relationship, UnionType};
+use bevy_abm::household::{Household, HouseholdMember};
+use bevy_abm::infection::{DiseaseState, InfectionEvent, TransmissionRoute, MaternalAntibodies,
+                          partner_transmission, household_transmission, progress_infection,
+                          log_vertical_transmission, wane_maternal_antibodies,
+                          VerticalTransmission, MaternalAntibodyTransfer, VERTICAL_INFECTION, MATERNAL_ANTIBODIES};
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+fn spawn_person(world: &mut World, sex: Sex, state: DiseaseState, household: Entity) -> Entity {
+    world.spawn((
+        Individual,
+        Demog { age: 30.0, sex },
+        state,
+        HouseholdMember(household)
+    )).id()
+}
+
+#[test]
+fn test_partner_and_household_transmission() {
+
+    let mut world = common::setup_world(SimulationParameters {
+        partner_transmission_rate: 10000.0,
+        household_transmission_rate: 0.0,
+        ..Default::default()
+    });
+    let home = world.spawn(Household).id();
+    let other_home = world.spawn(Household).id();
+    let infected = spawn_person(&mut world, Sex::Female, DiseaseState::Infected, home);
+    let partner = spawn_person(&mut world, Sex::Male, DiseaseState::Susceptible, other_home);
+    let housemate = spawn_person(&mut world, Sex::Female, DiseaseState::Susceptible, home);
+    world.spawn((Relationship, Couple { e1: infected, e2: partner, union_type: UnionType::Cohabitation }));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((partner_transmission, household_transmission).chain());
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<DiseaseState>(partner), Some(&DiseaseState::Infected), "Partner should be infected");
+    assert_eq!(world.get::<DiseaseState>(housemate), Some(&DiseaseState::Susceptible), "No household transmission at rate 0");
+    let events: Vec<InfectionEvent> = world.resource_mut::<Events<InfectionEvent>>().drain().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].source, Some(infected));
+    assert_eq!(events[0].route, TransmissionRoute::Partner);
+
+    // With household transmission on, the housemate catches it at home
+    world.resource_mut::<SimulationParameters>().household_transmission_rate = 10000.0;
+    schedule.run(&mut world);
+    assert_eq!(world.get::<DiseaseState>(housemate), Some(&DiseaseState::Infected), "Housemate should be infected");
+    let events: Vec<InfectionEvent> = world.resource_mut::<Events<InfectionEvent>>().drain().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].route, TransmissionRoute::Household);
+}
+
+#[test]
+fn test_recovery_and_disease_mortality() {
+
+    // SIS: recovered individuals become susceptible again
+    let mut world = common::setup_world(SimulationParameters {
+        recovery_rate: 10000.0,
+        infection_immunity: false,
+        ..Default::default()
+    });
+    let home = world.spawn(Household).id();
+    let e = spawn_person(&mut world, Sex::Female, DiseaseState::Infected, home);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((progress_infection, remove_dead).chain());
+    schedule.run(&mut world);
+    assert_eq!(world.get::<DiseaseState>(e), Some(&DiseaseState::Susceptible));
+
+    // SIR: recovery confers immunity
+    world.resource_mut::<SimulationParameters>().infection_immunity = true;
+    world.entity_mut(e).insert(DiseaseState::Infected);
+    schedule.run(&mut world);
+    assert_eq!(world.get::<DiseaseState>(e), Some(&DiseaseState::Recovered));
+
+    // Disease deaths are logged with their cause
+    world.resource_mut::<SimulationParameters>().infection_mortality_rate = 10000.0;
+    world.entity_mut(e).insert(DiseaseState::Infected);
+    schedule.run(&mut world);
+    assert!(world.get_entity(e).is_none(), "Infected individual should have died");
+    let deaths: Vec<DeathEvent> = world.resource_mut::<Events<DeathEvent>>().drain().collect();
+    assert_eq!(deaths.len(), 1);
+    assert_eq!(deaths[0].cause, DeathCause::Disease);
+}
+
+#[test]
+fn test_vertical_transmission_and_maternal_antibodies() {
+
+    let mut world = common::setup_world(SimulationParameters {
+        vertical_transmission_prob: 1.0,
+        maternal_antibody_prob: 1.0,
+        maternal_antibody_waning_rate: 10000.0,
+        ..Default::default()
+    });
+    let home = world.spawn(Household).id();
+    let infected_mother = spawn_person(&mut world, Sex::Female, DiseaseState::Infected, home);
+    let immune_mother = spawn_person(&mut world, Sex::Female, DiseaseState::Recovered, home);
+    for mother in [infected_mother, immune_mother] {
+        world.entity_mut(mother).insert(RemainingGestation(0.0));
+    }
+    world.insert_resource(MaternalTransferRules(vec![Box::new(VerticalTransmission), Box::new(MaternalAntibodyTransfer)]));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_gestation, log_vertical_transmission).chain());
+    schedule.run(&mut world);
+
+    let births: Vec<BirthEvent> = world.resource_mut::<Events<BirthEvent>>().drain().collect();
+    assert_eq!(births.len(), 2);
+    let infected_child = births.iter().find(|b| b.mother_entity == Some(infected_mother)).unwrap();
+    let protected_child = births.iter().find(|b| b.mother_entity == Some(immune_mother)).unwrap();
+    assert_eq!(infected_child.maternal_transfer, MaternalTransfer(vec![VERTICAL_INFECTION.to_string()]));
+    assert_eq!(protected_child.maternal_transfer, MaternalTransfer(vec![MATERNAL_ANTIBODIES.to_string()]));
+
+    assert_eq!(world.get::<DiseaseState>(infected_child.child_entity), Some(&DiseaseState::Infected));
+    assert_eq!(world.get::<DiseaseState>(protected_child.child_entity), Some(&DiseaseState::Recovered));
+    let infections: Vec<InfectionEvent> = world.resource_mut::<Events<InfectionEvent>>().drain().collect();
+    assert_eq!(infections.len(), 1);
+    assert_eq!(infections[0].route, TransmissionRoute::Vertical);
+    assert_eq!(infections[0].source, Some(infected_mother));
+
+    // Maternal antibodies wane, leaving the child susceptible
+    let mut waning_schedule = Schedule::default();
+    waning_schedule.add_systems(wane_maternal_antibodies);
+    waning_schedule.run(&mut world);
+    assert_eq!(world.get::<DiseaseState>(protected_child.child_entity), Some(&DiseaseState::Susceptible));
+    assert!(world.get::<MaternalAntibodies>(protected_child.child_entity).is_none());
+}
+
+// A user-defined attribute carried by some mothers and always passed on
+#[derive(Component)]
+struct Carrier;
+
+struct CarrierTransfer;
+
+impl MaternalTransferRule for CarrierTransfer {
+    fn attribute(&self) -> &str {
+        "carrier"
+    }
+
+    fn probability(&self, delivery: &Delivery) -> f32 {
+        if delivery.demog.age < 35.0 { 1.0 } else { 0.0 }
+    }
+
+    fn apply(&self, child: &mut EntityCommands) {
+        child.insert(Carrier);
+    }
+}
+
+#[test]
+fn test_registered_maternal_transfer_rule() {
+
+    let mut world = common::setup_world(SimulationParameters::default());
+    world.insert_resource(MaternalTransferRules(vec![Box::new(CarrierTransfer)]));
+    let home = world.spawn(Household).id();
+    let young_mother = spawn_person(&mut world, Sex::Female, DiseaseState::Susceptible, home);
+    let older_mother = spawn_person(&mut world, Sex::Female, DiseaseState::Susceptible, home);
+    world.entity_mut(older_mother).insert(Demog { age: 40.0, sex: Sex::Female });
+    for mother in [young_mother, older_mother] {
+        world.entity_mut(mother).insert(RemainingGestation(0.0));
+    }
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_gestation);
+    schedule.run(&mut world);
+
+    let births: Vec<BirthEvent> = world.resource_mut::<Events<BirthEvent>>().drain().collect();
+    let young_child = births.iter().find(|b| b.mother_entity == Some(young_mother)).unwrap();
+    let older_child = births.iter().find(|b| b.mother_entity == Some(older_mother)).unwrap();
+    assert!(young_child.maternal_transfer.received("carrier"));
+    assert!(world.get::<Carrier>(young_child.child_entity).is_some());
+    assert_eq!(older_child.maternal_transfer, MaternalTransfer::default());
+    assert!(world.get::<Carrier>(older_child.child_entity).is_none());
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex};
+use bevy_abm::gestation::Parity;
+use bevy_abm::intervention::{Interventions, FamilyPlanning, HazardModifier, Enrolment, EnrolmentEvent, enrol_interventions};
+
+// Spawn someone who has had `parity` children
+fn spawn_parent(world: &mut World, sex: Sex, parity: usize) -> Entity {
+    world.spawn((Individual, Demog { age: 30.0, sex }, Parity(parity))).id()
+}
+
+fn run_until(world: &mut World, schedule: &mut Schedule, years: f32) {
+    let elapsed = world.resource::<Time>().elapsed_seconds();
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(years - elapsed));
+    schedule.run(world);
+}
+
+#[test]
+fn test_family_planning_enrolment() {
+
+    // Women with 3+ children are offered family planning in years 10-20 with 60% coverage
+    let mut world = World::default();
+    world.init_resource::<Events<EnrolmentEvent>>();
+    world.init_resource::<Time>();
+    world.insert_resource(Interventions(vec![Box::new(FamilyPlanning {
+        start: 10.0,
+        end: 20.0,
+        coverage: 0.6,
+        min_parity: 3,
+        conception_factor: 0.5,
+    })]));
+
+    let eligible: Vec<Entity> = (0..500).map(|_| spawn_parent(&mut world, Sex::Female, 3)).collect();
+    let low_parity = spawn_parent(&mut world, Sex::Female, 2);
+    let father = spawn_parent(&mut world, Sex::Male, 4);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(enrol_interventions);
+
+    run_until(&mut world, &mut schedule, 5.0);
+    assert_eq!(world.resource::<Events<EnrolmentEvent>>().len(), 0, "No enrolment before the program starts");
+
+    run_until(&mut world, &mut schedule, 10.0);
+    run_until(&mut world, &mut schedule, 15.0);
+    let enrolled: Vec<Entity> = eligible.iter().copied().filter(|e| world.get::<HazardModifier>(*e).is_some()).collect();
+    assert!(enrolled.len() > 240 && enrolled.len() < 360, "About 60% of eligible women should enrol, got {}", enrolled.len());
+    assert_eq!(world.resource::<Events<EnrolmentEvent>>().len(), enrolled.len(), "Each enrolment is logged once");
+    assert_eq!(world.get::<HazardModifier>(enrolled[0]).unwrap().conception, 0.5);
+    assert!(eligible.iter().all(|e| world.get::<Enrolment>(*e).is_some()), "Every eligible woman decides on the first offer");
+    assert!(world.get::<Enrolment>(low_parity).is_none());
+    assert!(world.get::<Enrolment>(father).is_none());
+
+    run_until(&mut world, &mut schedule, 25.0);
+    assert!(enrolled.iter().all(|e| world.get::<HazardModifier>(*e).is_none()), "Effect ends with the program");
+}
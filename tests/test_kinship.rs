@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{BirthEvent, DeathEvent, DeathCause, MaternalTransfer, Sex};
+use bevy_abm::kinship::{Genealogy, record_births, record_deaths};
+
+#[test]
+fn test_genealogy_queries() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    world.init_resource::<Genealogy>();
+    world.init_resource::<Events<BirthEvent>>();
+    world.init_resource::<Events<DeathEvent>>();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((record_births, record_deaths).chain());
+
+    let mut born = |world: &mut World, mother: Option<Entity>, father: Option<Entity>, time: f32| {
+        let e = world.spawn_empty().id();
+        world.send_event(BirthEvent { child_entity: e, mother_entity: mother, father_entity: father, pregnancy_id: None, sex: Sex::Female, maternal_transfer: MaternalTransfer::default(), location: [0.0, 0.0], time });
+        schedule.run(world);
+        e
+    };
+
+    // Three generations: founders, their children (plus a half sibling) and grandchildren
+    let grandma = born(&mut world, None, None, 0.0);
+    let grandpa = born(&mut world, None, None, 0.0);
+    let other_man = born(&mut world, None, None, 0.0);
+    let in_law1 = born(&mut world, None, None, 0.0);
+    let in_law2 = born(&mut world, None, None, 0.0);
+
+    let mother = born(&mut world, Some(grandma), Some(grandpa), 1.0);
+    let aunt = born(&mut world, Some(grandma), Some(grandpa), 2.0);
+    let half_uncle = born(&mut world, Some(grandma), Some(other_man), 3.0);
+
+    let child = born(&mut world, Some(mother), Some(in_law1), 20.0);
+    let brother = born(&mut world, Some(mother), Some(in_law1), 21.0);
+    let cousin = born(&mut world, Some(aunt), Some(in_law2), 22.0);
+
+    let genealogy = world.resource::<Genealogy>();
+    assert_eq!(genealogy.parents(child), vec![mother, in_law1]);
+    assert_eq!(genealogy.children(mother), vec![child, brother]);
+    assert_eq!(genealogy.siblings(child), vec![brother]);
+    assert_eq!(genealogy.siblings(mother), vec![aunt]);
+    assert_eq!(genealogy.half_siblings(mother), vec![half_uncle]);
+    assert_eq!(genealogy.grandparents(child), vec![grandma, grandpa]);
+    assert_eq!(genealogy.cousins(child), vec![cousin]);
+    assert!(genealogy.cousins(brother).contains(&cousin));
+
+    let degrees = genealogy.kin_by_degree(child, 4);
+    assert_eq!(degrees.get(&mother), Some(&1));
+    assert_eq!(degrees.get(&brother), Some(&2));
+    assert_eq!(degrees.get(&grandpa), Some(&2));
+    assert_eq!(degrees.get(&aunt), Some(&3));
+    assert_eq!(degrees.get(&half_uncle), Some(&3));
+    assert_eq!(degrees.get(&cousin), Some(&4));
+    assert!(!degrees.contains_key(&in_law2), "Relatives by marriage are not consanguineous kin");
+
+    // Deaths are kept in the genealogy after the entity is gone
+    world.send_event(DeathEvent { entity: grandpa, age: 70.0, cause: DeathCause::OldAge, location: None, time: 25.0 });
+    world.despawn(grandpa);
+    schedule.run(&mut world);
+
+    let genealogy = world.resource::<Genealogy>();
+    assert_eq!(genealogy.grandparents(child), vec![grandma, grandpa], "Dead grandparents remain in the genealogy");
+    assert!(genealogy.is_alive(grandpa, 20.0), "Grandpa was alive at the child's birth");
+    assert!(!genealogy.is_alive(grandpa, 30.0));
+    assert!(!genealogy.is_alive(cousin, 21.0), "Cousin was not yet born");
+
+    let living = genealogy.living_kin_by_degree(child, 4, 30.0);
+    assert_eq!(living.get(&1), Some(&2), "Both parents alive");
+    assert_eq!(living.get(&2), Some(&2), "Brother and grandma alive, grandpa dead");
+    assert_eq!(living.get(&3), Some(&2), "Aunt and half uncle");
+    assert_eq!(living.get(&4), Some(&1), "One cousin");
+}
+
+#[test]
+fn test_emigrants_leave_living_kin() {
+
+    let mut world = World::default();
+    let mother = world.spawn_empty().id();
+    let child = world.spawn_empty().id();
+    let sister = world.spawn_empty().id();
+
+    let mut genealogy = Genealogy::default();
+    genealogy.add_birth(mother, None, None, 0.0);
+    genealogy.add_birth(child, Some(mother), None, 20.0);
+    genealogy.add_birth(sister, Some(mother), None, 22.0);
+    genealogy.add_exit(sister, 40.0);
+
+    assert!(genealogy.is_alive(sister, 30.0));
+    assert!(!genealogy.is_alive(sister, 41.0), "Emigrants are no longer in the population");
+    assert_eq!(genealogy.living_kin_by_degree(child, 2, 30.0).get(&2), Some(&1));
+    assert_eq!(genealogy.living_kin_by_degree(child, 2, 41.0).get(&2), Some(&0));
+    assert_eq!(genealogy.children(mother), vec![child, sister], "Emigrants stay in the genealogy");
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult, BirthEvent};
+use bevy_abm::partner::{Partner, PartnerSeeking, Couple, Relationship, UnionType, PartnerEvent, AvailableSeekers,
+                        start_partner_seeking, queue_partner_seekers, match_partners, resolve_matches};
+use bevy_abm::gestation::{RemainingGestation, StillbirthEvent, PregnancyEndEvent, update_gestation};
+use bevy_abm::location::{Location, NEWBORN_SPREAD};
+use bevy_abm::config::SimulationParameters;
+
+#[test]
+fn test_newborn_placed_near_mother() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        grid_width: 100.0,
+        grid_height: 100.0,
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.init_resource::<Events<BirthEvent>>();
+    world.init_resource::<Events<StillbirthEvent>>();
+    world.init_resource::<Events<PregnancyEndEvent>>();
+    world.init_resource::<Time>();
+
+    // Pregnant female about to give birth in the middle of the grid
+    let mother_location = Location(Vec2::new(50.0, 50.0));
+    world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 25.0, sex: Sex::Female },
+        mother_location,
+        RemainingGestation(0.0)
+    ));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_gestation);
+    schedule.run(&mut world);
+
+    let birth_events = world.resource::<Events<BirthEvent>>();
+    let mut reader = birth_events.get_reader();
+    let birth = reader.read(birth_events).next().expect("Should have emitted a birth event");
+    let child = birth.child_entity;
+    let birth_location = birth.location;
+
+    let child_location = *world.get::<Location>(child).expect("Newborn should have a Location");
+    assert_eq!(child_location.coords(), birth_location, "Birth event should carry the newborn's coordinates");
+    assert!((child_location.0 - mother_location.0).abs().max_element() <= NEWBORN_SPREAD / 2.0,
+        "Newborn should be placed near the mother");
+}
+
+#[test]
+fn test_partners_move_to_shared_home() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        grid_width: 10.0,
+        grid_height: 10.0,
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Time>();
+
+    // A matched couple living at opposite corners of the grid
+    let female = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 25.0, sex: Sex::Female },
+        Location(Vec2::new(0.0, 0.0)),
+        PartnerSeeking
+    )).id();
+    let male = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 25.0, sex: Sex::Male },
+        Location(Vec2::new(10.0, 10.0)),
+        PartnerSeeking
+    )).id();
+    world.spawn((Relationship, Couple { e1: female, e2: male, union_type: UnionType::Cohabitation }));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(resolve_matches);
+    schedule.run(&mut world);
+
+    let home = *world.get::<Location>(female).unwrap();
+    assert_eq!(world.get::<Location>(male), Some(&home), "Partners should share a home");
+    for coord in home.coords() {
+        assert!((0.0..=10.0).contains(&coord), "Home should lie within the grid bounds");
+    }
+
+    let partner_events = world.resource::<Events<PartnerEvent>>();
+    let mut reader = partner_events.get_reader();
+    let event = reader.read(partner_events).next().expect("Should have emitted a partner event");
+    assert_eq!(event.location, Some(home.coords()), "Partner event should carry the home coordinates");
+}
+
+#[test]
+fn test_spatial_matching_within_radius() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        grid_width: 20.0,
+        grid_height: 20.0,
+        spatial_matching: true,
+        partner_search_radius: 2.0,
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.insert_resource(AvailableSeekers::default());
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Time>();
+
+    // One woman, a man far away and a man living close by
+    let spawn_adult = |world: &mut World, sex: Sex, x: f32, y: f32| {
+        world.spawn((
+            Individual,
+            Adult,
+            Demog { age: 25.0, sex },
+            Location(Vec2::new(x, y))
+        )).id()
+    };
+    let female = spawn_adult(&mut world, Sex::Female, 0.0, 0.0);
+    let far_male = spawn_adult(&mut world, Sex::Male, 15.0, 15.0);
+    let near_male = spawn_adult(&mut world, Sex::Male, 1.0, 1.0);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((
+        start_partner_seeking,
+        queue_partner_seekers,
+        match_partners,
+        resolve_matches
+    ).chain());
+    for _ in 0..5 {
+        schedule.run(&mut world);
+    }
+
+    assert_eq!(world.get::<Partner>(female).map(|p| p.0), Some(near_male), "Woman should partner with the man within the radius");
+    assert!(world.get::<Partner>(far_male).is_none(), "Man outside the search radius should stay single");
+
+    let partner_events = world.resource::<Events<PartnerEvent>>();
+    let mut reader = partner_events.get_reader();
+    let events: Vec<&PartnerEvent> = reader.read(partner_events).collect();
+    assert_eq!(events.len(), 1, "Should have emitted 1 partner event");
+    let distance = events[0].distance.expect("Partner event should record the pre-union distance");
+    assert!((distance - 2.0_f32.sqrt()).abs() < 1e-5, "Distance should be measured before moving to the shared home");
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, BirthEvent};
+use bevy_abm::gestation::{RemainingGestation, Pregnancy, MultipleBirthRate, PregnancyEndEvent,
+                          update_gestation, draw_birth_count};
+use bevy_abm::events::{deliveries_by_size, mean_birth_interval};
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+#[test]
+fn test_twins_share_a_pregnancy() {
+
+    // Mothers aged 30-40 always have twins, younger ones singletons
+    let rates = vec![MultipleBirthRate { min_age: 30.0, max_age: 40.0, twins: 1.0, triplets: 0.0 }];
+    assert_eq!(draw_birth_count(&rates, 35.0), 2);
+    assert_eq!(draw_birth_count(&rates, 25.0), 1);
+    assert_eq!(draw_birth_count(&[MultipleBirthRate { min_age: 0.0, max_age: 50.0, twins: 0.0, triplets: 1.0 }], 25.0), 3);
+
+    let mut world = common::setup_world(SimulationParameters {
+        multiple_birth_rates: rates,
+        ..Default::default()
+    });
+
+    let father = world.spawn((Individual, Demog { age: 35.0, sex: Sex::Male })).id();
+    for (id, age) in [(7, 35.0), (8, 25.0)] {
+        world.spawn((
+            Individual,
+            Demog { age, sex: Sex::Female },
+            RemainingGestation(0.0),
+            Pregnancy { id, father, duration: 40.0 / 52.0 }
+        ));
+    }
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_gestation);
+    schedule.run(&mut world);
+
+    let births: Vec<BirthEvent> = world.resource_mut::<Events<BirthEvent>>().drain().collect();
+    assert_eq!(births.len(), 3);
+    let twins: Vec<&BirthEvent> = births.iter().filter(|b| b.pregnancy_id == Some(7)).collect();
+    assert_eq!(twins.len(), 2, "Older mother should have twins");
+    assert_ne!(twins[0].child_entity, twins[1].child_entity);
+    assert!(twins.iter().all(|b| b.father_entity == Some(father)));
+    assert_eq!(world.resource::<Events<PregnancyEndEvent>>().len(), 2, "One outcome per pregnancy");
+
+    assert_eq!(deliveries_by_size(&births), vec![(1, 1), (2, 1)]);
+    assert_eq!(mean_birth_interval(&births), None, "Twins are a single delivery, not a zero-year interval");
+
+    // A later singleton to the mother of twins gives one interval
+    let mut later = twins[0].clone();
+    later.pregnancy_id = Some(9);
+    later.time += 2.0;
+    let mut births = births.clone();
+    births.push(later);
+    assert_eq!(mean_birth_interval(&births), Some(2.0));
+}
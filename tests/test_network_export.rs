@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+
+use bevy_abm::individual::{BirthEvent, DeathEvent, DeathCause, MaternalTransfer, Sex};
+use bevy_abm::partner::{PartnerEvent, MarriageEvent, WidowEvent, UnionType};
+use bevy_abm::emigration::EmigrationEvent;
+use bevy_abm::events::EventLog;
+use bevy_abm::network::{PedigreeNetwork, EdgeKind};
+
+#[test]
+fn test_network_from_event_log() {
+
+    // A founder couple who marry, have a child, and the father dies
+    let mut world = World::default();
+    let mother = world.spawn_empty().id();
+    let father = world.spawn_empty().id();
+    let child = world.spawn_empty().id();
+    let relationship = world.spawn_empty().id();
+
+    let event_log = EventLog {
+        births: vec![
+            BirthEvent { child_entity: mother, mother_entity: None, father_entity: None, pregnancy_id: None, sex: Sex::Female, maternal_transfer: MaternalTransfer::default(), location: [0.0, 0.0], time: 0.0 },
+            BirthEvent { child_entity: father, mother_entity: None, father_entity: None, pregnancy_id: None, sex: Sex::Male, maternal_transfer: MaternalTransfer::default(), location: [0.0, 0.0], time: 0.0 },
+            BirthEvent { child_entity: child, mother_entity: Some(mother), father_entity: Some(father), pregnancy_id: None, sex: Sex::Male, maternal_transfer: MaternalTransfer::default(), location: [0.0, 0.0], time: 3.0 },
+        ],
+        deaths: vec![DeathEvent { entity: father, age: 70.0, cause: DeathCause::OldAge, location: None, time: 10.0 }],
+        partnerships: vec![PartnerEvent {
+            individual1: mother,
+            individual2: father,
+            relationship_entity: relationship,
+            union_type: UnionType::Cohabitation,
+            location: None,
+            distance: None,
+            time: 1.0,
+        }],
+        marriages: vec![MarriageEvent { individual1: mother, individual2: father, relationship_entity: relationship, time: 2.0 }],
+        widowings: vec![WidowEvent { widow_entity: mother, deceased_entity: father, relationship_entity: relationship, time: 10.0 }],
+        ..Default::default()
+    };
+
+    let network = PedigreeNetwork::from_event_log(&event_log);
+    assert_eq!(network.nodes.len(), 3);
+    assert_eq!(network.edges.len(), 3, "Two parent-child edges and one partnership");
+
+    let father_node = network.nodes.iter().find(|n| n.entity == father).unwrap();
+    assert!(father_node.founder);
+    assert_eq!(father_node.sex, Sex::Male);
+    assert_eq!(father_node.death_time, Some(10.0));
+    let child_node = network.nodes.iter().find(|n| n.entity == child).unwrap();
+    assert!(!child_node.founder);
+    assert_eq!(child_node.death_time, None);
+
+    let mother_edge = network.edges.iter().find(|e| e.kind == EdgeKind::Mother).unwrap();
+    assert_eq!((mother_edge.source, mother_edge.target), (mother, child));
+    let partner_edge = network.edges.iter().find(|e| e.kind == EdgeKind::Partner).unwrap();
+    assert_eq!(partner_edge.union_type, Some(UnionType::Marriage), "Union converted to marriage");
+    assert_eq!(partner_edge.start, 1.0);
+    assert_eq!(partner_edge.end, Some(10.0), "Partnership ends at widowhood");
+
+    // Serialized formats contain every node and edge
+    let graphml = network.to_graphml();
+    assert_eq!(graphml.matches("<node ").count(), 3);
+    assert_eq!(graphml.matches("<edge ").count(), 3);
+    assert!(graphml.contains("directed=\"false\""), "Partner edges are undirected");
+
+    let gexf = network.to_gexf();
+    assert_eq!(gexf.matches("<node ").count(), 3);
+    assert!(gexf.contains(&format!("<node id=\"{0}\" label=\"{0}\" start=\"0\" end=\"10\">", father.to_bits())));
+
+    assert_eq!(network.nodes_csv().lines().count(), 4, "Header plus one line per node");
+    assert_eq!(network.edges_csv().lines().count(), 4, "Header plus one line per edge");
+}
+
+#[test]
+fn test_emigration_ends_node_and_partnership() {
+
+    let mut world = World::default();
+    let stayer = world.spawn_empty().id();
+    let emigrant = world.spawn_empty().id();
+    let relationship = world.spawn_empty().id();
+
+    let event_log = EventLog {
+        births: vec![
+            BirthEvent { child_entity: stayer, mother_entity: None, father_entity: None, pregnancy_id: None, sex: Sex::Female, maternal_transfer: MaternalTransfer::default(), location: [0.0, 0.0], time: 0.0 },
+            BirthEvent { child_entity: emigrant, mother_entity: None, father_entity: None, pregnancy_id: None, sex: Sex::Male, maternal_transfer: MaternalTransfer::default(), location: [0.0, 0.0], time: 0.0 },
+        ],
+        partnerships: vec![PartnerEvent {
+            individual1: stayer,
+            individual2: emigrant,
+            relationship_entity: relationship,
+            union_type: UnionType::Cohabitation,
+            location: None,
+            distance: None,
+            time: 1.0,
+        }],
+        emigrations: vec![EmigrationEvent { entity: emigrant, age: 30.0, sex: Sex::Male, location: None, patch: None, time: 5.0 }],
+        ..Default::default()
+    };
+
+    let network = PedigreeNetwork::from_event_log(&event_log);
+    let emigrant_node = network.nodes.iter().find(|n| n.entity == emigrant).unwrap();
+    assert_eq!(emigrant_node.exit_time, Some(5.0));
+    assert_eq!(emigrant_node.death_time, None);
+    let partner_edge = network.edges.iter().find(|e| e.kind == EdgeKind::Partner).unwrap();
+    assert_eq!(partner_edge.end, Some(5.0), "Partnership ends when a partner emigrates");
+    assert!(network.to_gexf().contains(&format!("<node id=\"{0}\" label=\"{0}\" start=\"0\" end=\"5\">", emigrant.to_bits())));
+}
@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult, DeathEvent, DeathCause};
+use bevy_abm::partner::{BreakupEvent, UnionType};
+use bevy_abm::gestation::{Mother, Father};
+use bevy_abm::household::{HouseholdMember, CustodyEvent, split_households_on_breakup};
+use bevy_abm::orphanhood::{Orphanhood, OrphanhoodEvent, OrphanhoodKind, OrphanhoodPlugin, detect_orphans, detect_posthumous_births};
+use bevy_abm::kinship::Genealogy;
+use bevy_abm::config::{SimulationParameters, CustodyRule};
+
+fn spawn_parent(world: &mut World, sex: Sex, household: Entity) -> Entity {
+    world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 35.0, sex },
+        HouseholdMember(household)
+    )).id()
+}
+
+fn spawn_child(world: &mut World, age: f32, mother: Entity, father: Entity, household: Entity) -> Entity {
+    world.spawn((
+        Individual,
+        Demog { age, sex: Sex::Female },
+        Mother(mother),
+        Father(father),
+        HouseholdMember(household)
+    )).id()
+}
+
+fn kill(world: &mut World, e: Entity) {
+    world.send_event(DeathEvent { entity: e, age: 35.0, cause: DeathCause::OldAge, location: None, time: 0.0 });
+    world.despawn(e);
+}
+
+#[test]
+fn test_maternal_then_double_orphanhood() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        orphan_age_limit: 18.0,
+        ..Default::default()
+    });
+    world.init_resource::<Events<DeathEvent>>();
+    world.init_resource::<Events<OrphanhoodEvent>>();
+    world.init_resource::<Time>();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(detect_orphans);
+
+    let household = world.spawn_empty().id();
+    let mother = spawn_parent(&mut world, Sex::Female, household);
+    let father = spawn_parent(&mut world, Sex::Male, household);
+    let child = spawn_child(&mut world, 5.0, mother, father, household);
+    let adult_child = spawn_child(&mut world, 20.0, mother, father, household);
+
+    // Mother dies: both children lose a parent, only the minor is reported
+    kill(&mut world, mother);
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<Orphanhood>(child), Some(&Orphanhood { maternal: true, paternal: false }));
+    assert_eq!(world.get::<Orphanhood>(adult_child), Some(&Orphanhood { maternal: true, paternal: false }),
+        "Parental survival should be tracked at any age");
+
+    let events: Vec<OrphanhoodEvent> = world.resource_mut::<Events<OrphanhoodEvent>>().drain().collect();
+    assert_eq!(events.len(), 1, "Only children below orphan_age_limit should emit an orphanhood event");
+    assert_eq!(events[0].child_entity, child);
+    assert_eq!(events[0].kind, OrphanhoodKind::Maternal);
+    assert_eq!(events[0].deceased_parent, mother);
+
+    // Father dies too: the child becomes a double orphan
+    kill(&mut world, father);
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<Orphanhood>(child).and_then(|o| o.kind()), Some(OrphanhoodKind::Double));
+    let events: Vec<OrphanhoodEvent> = world.resource_mut::<Events<OrphanhoodEvent>>().drain().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, OrphanhoodKind::Double);
+    assert_eq!(events[0].deceased_parent, father);
+}
+
+
+#[test]
+fn test_both_parents_die_in_the_same_step() {
+
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        orphan_age_limit: 18.0,
+        ..Default::default()
+    });
+    world.init_resource::<Events<DeathEvent>>();
+    world.init_resource::<Events<OrphanhoodEvent>>();
+    world.init_resource::<Time>();
+
+    let household = world.spawn_empty().id();
+    let mother = spawn_parent(&mut world, Sex::Female, household);
+    let father = spawn_parent(&mut world, Sex::Male, household);
+    let child = spawn_child(&mut world, 5.0, mother, father, household);
+
+    kill(&mut world, mother);
+    kill(&mut world, father);
+    let mut schedule = Schedule::default();
+    schedule.add_systems(detect_orphans);
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<Orphanhood>(child).and_then(|o| o.kind()), Some(OrphanhoodKind::Double));
+    let events: Vec<OrphanhoodEvent> = world.resource_mut::<Events<OrphanhoodEvent>>().drain().collect();
+    let reported: Vec<(Entity, OrphanhoodKind)> = events.iter().map(|e| (e.deceased_parent, e.kind)).collect();
+    assert_eq!(reported, vec![(mother, OrphanhoodKind::Maternal), (father, OrphanhoodKind::Double)],
+        "Each deceased parent should be reported");
+}
+#[test]
+fn test_posthumous_birth_after_emigration() {
+
+    // Two fathers gone before their children are born: one died, the other emigrated
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        orphan_age_limit: 18.0,
+        ..Default::default()
+    });
+    world.init_resource::<Genealogy>();
+    world.init_resource::<Events<OrphanhoodEvent>>();
+    world.init_resource::<Time>();
+
+    let household = world.spawn_empty().id();
+    let mother = spawn_parent(&mut world, Sex::Female, household);
+    let dead_father = spawn_parent(&mut world, Sex::Male, household);
+    let emigrant_father = spawn_parent(&mut world, Sex::Male, household);
+    {
+        let mut genealogy = world.resource_mut::<Genealogy>();
+        for e in [mother, dead_father, emigrant_father] {
+            genealogy.add_birth(e, None, None, 0.0);
+        }
+        genealogy.add_death(dead_father, 1.0);
+        genealogy.add_exit(emigrant_father, 1.0);
+    }
+    world.despawn(dead_father);
+    world.despawn(emigrant_father);
+
+    let orphan = spawn_child(&mut world, 0.0, mother, dead_father, household);
+    let emigrant_child = spawn_child(&mut world, 0.0, mother, emigrant_father, household);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(detect_posthumous_births);
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<Orphanhood>(orphan).and_then(|o| o.kind()), Some(OrphanhoodKind::Paternal));
+    assert!(world.get::<Orphanhood>(emigrant_child).is_none(), "A father who emigrated is not deceased");
+    assert_eq!(world.resource::<Events<OrphanhoodEvent>>().len(), 1);
+}
+
+#[test]
+fn test_custody_rule_on_breakup() {
+
+    for (rule, expect_father) in [(CustodyRule::Mother, false), (CustodyRule::Father, true)] {
+        let mut world = World::default();
+        world.insert_resource(SimulationParameters {
+            custody_rule: rule,
+            ..Default::default()
+        });
+        world.init_resource::<Events<BreakupEvent>>();
+        world.init_resource::<Events<CustodyEvent>>();
+        world.init_resource::<Time>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(split_households_on_breakup);
+
+        let household = world.spawn_empty().id();
+        let mother = spawn_parent(&mut world, Sex::Female, household);
+        let father = spawn_parent(&mut world, Sex::Male, household);
+        let child = spawn_child(&mut world, 5.0, mother, father, household);
+
+        world.send_event(BreakupEvent {
+            partner1: mother,
+            partner2: father,
+            relationship_entity: Entity::PLACEHOLDER,
+            union_type: UnionType::Cohabitation,
+            location: None,
+            time: 0.0,
+        });
+        schedule.run(&mut world);
+
+        let father_household = world.get::<HouseholdMember>(father).unwrap().0;
+        let child_household = world.get::<HouseholdMember>(child).unwrap().0;
+        assert_ne!(father_household, household, "Father should move out after breakup");
+        assert_eq!(world.get::<HouseholdMember>(mother).unwrap().0, household, "Mother should keep the household");
+
+        let custodian = if expect_father { father } else { mother };
+        let custodian_household = world.get::<HouseholdMember>(custodian).unwrap().0;
+        assert_eq!(child_household, custodian_household, "Child should live with the custodian under {:?}", rule);
+
+        let events: Vec<CustodyEvent> = world.resource_mut::<Events<CustodyEvent>>().drain().collect();
+        assert_eq!(events.len(), 1, "Should have emitted 1 custody event");
+        assert_eq!(events[0].child_entity, child);
+        assert_eq!(events[0].custodian, custodian);
+    }
+}
+
+#[test]
+fn test_father_dies_as_child_is_born() {
+
+    // Run the plugin's own ordering of detect_orphans and detect_posthumous_births
+    let mut app = App::new();
+    app.add_plugins(OrphanhoodPlugin)
+        .insert_resource(SimulationParameters {
+            orphan_age_limit: 18.0,
+            ..Default::default()
+        })
+        .add_event::<DeathEvent>()
+        .init_resource::<Time>();
+
+    let world = &mut app.world;
+    let household = world.spawn_empty().id();
+    let mother = spawn_parent(world, Sex::Female, household);
+    let father = spawn_parent(world, Sex::Male, household);
+    let newborn = spawn_child(world, 0.0, mother, father, household);
+    kill(world, father);
+    app.update();
+
+    let events: Vec<OrphanhoodEvent> = app.world.resource_mut::<Events<OrphanhoodEvent>>().drain().collect();
+    assert_eq!(events.len(), 1, "The father's death should be reported once");
+    assert_eq!(events[0].child_entity, newborn);
+    assert_eq!(events[0].kind, OrphanhoodKind::Paternal);
+    assert_eq!(app.world.get::<Orphanhood>(newborn).and_then(|o| o.kind()), Some(OrphanhoodKind::Paternal));
+}
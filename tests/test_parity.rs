@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex};
+use bevy_abm::partner::Partner;
+use bevy_abm::gestation::{RemainingGestation, Parity, DesiredFamilySize, MultipleBirthRate,
+                          FertilityCompleted, CompletedFertilityEvent, update_gestation, conception, complete_fertility};
+use bevy_abm::events::cohort_fertility;
+use bevy_abm::patch::{PatchParameters, InPatch};
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+fn spawn_couple(world: &mut World, parity: usize) -> Entity {
+    let father = world.spawn((Individual, Demog { age: 30.0, sex: Sex::Male })).id();
+    world.spawn((Individual, Demog { age: 30.0, sex: Sex::Female }, Partner(father), Parity(parity))).id()
+}
+
+#[test]
+fn test_parity_counts_live_births() {
+
+    let mut world = common::setup_world(SimulationParameters {
+        multiple_birth_rates: vec![MultipleBirthRate { min_age: 0.0, max_age: 100.0, twins: 1.0, triplets: 0.0 }],
+        ..Default::default()
+    });
+    let mother = world.spawn((Individual, Demog { age: 30.0, sex: Sex::Female }, RemainingGestation(0.0))).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_gestation);
+    schedule.run(&mut world);
+    assert_eq!(world.get::<Parity>(mother).unwrap().0, 2, "Twins count as two children");
+
+    world.entity_mut(mother).insert(RemainingGestation(0.0));
+    schedule.run(&mut world);
+    assert_eq!(world.get::<Parity>(mother).unwrap().0, 4);
+}
+
+#[test]
+fn test_parity_specific_fertility_and_stopping() {
+
+    // Women with one child no longer conceive
+    let mut world = common::setup_world(SimulationParameters {
+        conception_rate: 10000.0,
+        parity_fertility_ratios: vec![1.0, 0.0],
+        ..Default::default()
+    });
+    let childless = spawn_couple(&mut world, 0);
+    let mother = spawn_couple(&mut world, 1);
+    let mut schedule = Schedule::default();
+    schedule.add_systems(conception);
+    schedule.run(&mut world);
+    assert!(world.get::<RemainingGestation>(childless).is_some());
+    assert!(world.get::<RemainingGestation>(mother).is_none(), "Ratio 0 should stop conception at parity 1+");
+
+    // Everyone wants exactly two children
+    let mut world = common::setup_world(SimulationParameters {
+        conception_rate: 10000.0,
+        desired_family_size: 2.0,
+        ..Default::default()
+    });
+    let below_target = spawn_couple(&mut world, 1);
+    let at_target = spawn_couple(&mut world, 2);
+    let mut schedule = Schedule::default();
+    schedule.add_systems(conception);
+    schedule.run(&mut world);
+    assert!(world.get::<RemainingGestation>(below_target).is_some());
+    assert!(world.get::<RemainingGestation>(at_target).is_none(), "No conception after reaching the desired family size");
+    assert_eq!(world.get::<DesiredFamilySize>(at_target).unwrap().0, 2);
+}
+
+#[test]
+fn test_completed_cohort_fertility() {
+
+    let mut world = common::setup_world(SimulationParameters {
+        max_conception_age: 35.0,
+        ..Default::default()
+    });
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(50.0));
+    world.spawn((Individual, Demog { age: 36.0, sex: Sex::Female }, Parity(3)));
+    world.spawn((Individual, Demog { age: 40.0, sex: Sex::Female }));
+    world.spawn((Individual, Demog { age: 30.0, sex: Sex::Female }, Parity(1)));
+    world.spawn((Individual, Demog { age: 40.0, sex: Sex::Male }));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(complete_fertility);
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+
+    let records: Vec<CompletedFertilityEvent> = world.resource_mut::<Events<CompletedFertilityEvent>>().drain().collect();
+    assert_eq!(records.len(), 2, "Each woman past max_conception_age is recorded once");
+
+    // Born at time 14 and 10, both in the cohort starting at 10
+    assert_eq!(cohort_fertility(&records, 10.0), vec![(10.0, vec![1, 0, 0, 1])]);
+    assert_eq!(cohort_fertility(&records, 2.0), vec![(10.0, vec![1]), (14.0, vec![0, 0, 0, 1])]);
+}
+
+#[test]
+fn test_fertility_completed_after_last_pregnancy() {
+
+    let mut world = common::setup_world(SimulationParameters {
+        max_conception_age: 35.0,
+        ..Default::default()
+    });
+    let mother = world.spawn((Individual, Demog { age: 35.5, sex: Sex::Female }, Parity(1), RemainingGestation(0.1))).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_gestation, complete_fertility).chain());
+    schedule.run(&mut world);
+    assert!(world.resource::<Events<CompletedFertilityEvent>>().is_empty(), "Not recorded while still pregnant");
+
+    // The birth after the cutoff counts towards her completed fertility
+    world.entity_mut(mother).insert(RemainingGestation(0.0));
+    schedule.run(&mut world);
+    let records: Vec<CompletedFertilityEvent> = world.resource_mut::<Events<CompletedFertilityEvent>>().drain().collect();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].parity, 2);
+}
+
+#[test]
+fn test_fertility_completed_at_patch_cutoff() {
+
+    // Women in the patch can conceive until 40, elsewhere until 35
+    let params = SimulationParameters { max_conception_age: 35.0, ..Default::default() };
+    let mut world = common::setup_world(params.clone());
+    world.insert_resource(PatchParameters(vec![SimulationParameters { max_conception_age: 40.0, ..params }]));
+    let in_patch = world.spawn((Individual, Demog { age: 37.0, sex: Sex::Female }, InPatch(0))).id();
+    let outside = world.spawn((Individual, Demog { age: 37.0, sex: Sex::Female })).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(complete_fertility);
+    schedule.run(&mut world);
+    let records: Vec<CompletedFertilityEvent> = world.resource_mut::<Events<CompletedFertilityEvent>>().drain().collect();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].entity, outside);
+    assert!(world.get::<FertilityCompleted>(in_patch).is_none(), "The patch's later cutoff applies");
+}
@@ -6,7 +6,7 @@ use bevy_abm::partner::{Partner, start_partner_seeking,
                         queue_partner_seekers, match_partners, resolve_matches,
                         random_breakups, detect_widows, AvailableSeekers,
                         Relationship, BreakupEvent, PartnerEvent, WidowEvent};
-use bevy_abm::gestation::{conception, RemainingGestation, update_gestation};
+use bevy_abm::gestation::{conception, RemainingGestation, StillbirthEvent, PregnancyEndEvent, update_gestation};
 use bevy_abm::config::SimulationParameters;
 
 #[test]
@@ -30,6 +30,8 @@ fn test_death_stops_conception() {
     world.init_resource::<Events<PartnerEvent>>();
     world.init_resource::<Events<WidowEvent>>();
     world.init_resource::<Events<BirthEvent>>();
+    world.init_resource::<Events<StillbirthEvent>>();
+    world.init_resource::<Events<PregnancyEndEvent>>();
     world.init_resource::<Time>();
 
     // Create entities: 1 male, 1 female - both adults, conception-ready age
@@ -151,6 +153,8 @@ fn test_breakup_stops_conception() {
     world.init_resource::<Events<PartnerEvent>>();
     world.init_resource::<Events<WidowEvent>>();
     world.init_resource::<Events<BirthEvent>>();
+    world.init_resource::<Events<StillbirthEvent>>();
+    world.init_resource::<Events<PregnancyEndEvent>>();
     world.init_resource::<Time>();
 
     // Create entities: 1 male, 1 female - both adults, conception-ready age
@@ -7,8 +7,9 @@ use bevy::ecs::event::Events;
 use bevy_abm::individual::{Individual, Demog, Sex, Adult, Elder, update_age, DeathEvent};
 use bevy_abm::partner::{PartnerSeeking, start_partner_seeking, stop_elder_partner_seeking,
                         queue_partner_seekers, match_partners, resolve_matches,
-                        AvailableSeekers, Partner, Relationship, Partners,
-                        detect_widows, BreakupEvent, PartnerEvent, WidowEvent};
+                        AvailableSeekers, Partner, Relationship, Couple, Orientation,
+                        detect_widows, BreakupEvent, PartnerEvent, WidowEvent,
+                        UnionType, ReentryDelay, update_reentry_delay};
 use bevy_abm::config::SimulationParameters;
 
 #[test]
@@ -190,7 +191,7 @@ fn test_partner_matching_with_uneven_ratios() {
     assert_eq!(seekers.males.len(), 1, "Should have 1 male in queue");
 
     // Verify the relationship contains the expected entities
-    let mut relationship_query = world.query::<&Partners>();
+    let mut relationship_query = world.query::<&Couple>();
     let partners = relationship_query.single(&world);
 
     // One of the partners should be the female, the other should be one of the males
@@ -222,7 +223,9 @@ fn test_simultaneous_partner_death() {
         conception_rate: 0.0, // Not testing conception
         gestation_duration: 40.0 / 52.0,
         breakup_rate: 0.0, // No breakups for this test
+        divorce_rate: 0.0,
         spawn_individual_age: 18.0,
+        ..Default::default()
     };
     world.insert_resource(params);
     world.insert_resource(AvailableSeekers::default());
@@ -287,4 +290,142 @@ fn test_simultaneous_partner_death() {
     // Verify relationship entity is cleaned up (should only happen once, not cause panic)
     let relationships_after = world.query_filtered::<Entity, With<Relationship>>().iter(&world).count();
     assert_eq!(relationships_after, 0, "Relationship should be cleaned up after simultaneous deaths");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_same_sex_partner_matching() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        min_partner_seeking_age: 20.0,
+        max_partner_seeking_age: 50.0,
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.insert_resource(AvailableSeekers::default());
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Time>();
+
+    // Create entities: 2 homosexual females and 1 heterosexual male
+    let female1 = world.spawn((
+        Individual,
+        Adult,
+        Orientation::Homosexual,
+        Demog { age: 25.0, sex: Sex::Female }
+    )).id();
+
+    let female2 = world.spawn((
+        Individual,
+        Adult,
+        Orientation::Homosexual,
+        Demog { age: 27.0, sex: Sex::Female }
+    )).id();
+
+    let male1 = world.spawn((
+        Individual,
+        Adult,
+        Orientation::Heterosexual,
+        Demog { age: 26.0, sex: Sex::Male }
+    )).id();
+
+    // Setup and run the full partner matching pipeline
+    let mut schedule = Schedule::default();
+    schedule.add_systems((
+        start_partner_seeking,
+        queue_partner_seekers,
+        match_partners,
+        resolve_matches
+    ));
+
+    for _ in 0..10 {
+        schedule.run(&mut world);
+        if world.get::<Partner>(female1).is_some() && world.get::<Partner>(female2).is_some() {
+            break;
+        }
+    }
+
+    // The two females should be partnered with each other
+    assert_eq!(world.get::<Partner>(female1).map(|p| p.0), Some(female2), "Female1 should be partnered with female2");
+    assert_eq!(world.get::<Partner>(female2).map(|p| p.0), Some(female1), "Female2 should be partnered with female1");
+
+    // The heterosexual male has no compatible seeker and keeps waiting
+    assert!(world.get::<Partner>(male1).is_none(), "Male should not have Partner component");
+    assert!(world.get::<PartnerSeeking>(male1).is_some(), "Male should still be seeking");
+
+    let seekers = world.resource::<AvailableSeekers>();
+    assert_eq!(seekers.males, vec![male1], "Male should remain in the opposite-sex queue");
+    assert!(seekers.same_sex_females.is_empty(), "Same-sex female queue should be empty");
+}
+
+#[test]
+fn test_orientation_fractions_validated() {
+
+    let mut params = SimulationParameters {
+        homosexual_fraction: 0.3,
+        bisexual_fraction: 0.5,
+        ..Default::default()
+    };
+    assert!(params.validate().is_ok());
+
+    // Fractions beyond 1 in total would leave no heterosexual share to draw
+    params.bisexual_fraction = 0.8;
+    assert!(params.validate().is_err(), "homosexual_fraction + bisexual_fraction > 1 should be rejected");
+
+    params.homosexual_fraction = -0.1;
+    params.bisexual_fraction = 0.1;
+    assert!(params.validate().is_err(), "Negative fractions should be rejected");
+}
+
+#[test]
+fn test_widowhood_reentry_delay() {
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        min_partner_seeking_age: 20.0,
+        max_partner_seeking_age: 50.0,
+        widowhood_reentry_delay: 1.0, // 1 year of mourning
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.init_resource::<Events<WidowEvent>>();
+    world.init_resource::<Time>();
+
+    // Create an existing couple
+    let male1 = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 35.0, sex: Sex::Male },
+    )).id();
+    let female1 = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 34.0, sex: Sex::Female },
+        Partner(male1),
+    )).id();
+    world.entity_mut(male1).insert(Partner(female1));
+    world.spawn((Relationship, Couple { e1: female1, e2: male1, union_type: UnionType::Cohabitation }));
+
+    // Male dies and the widow is detected
+    world.despawn(male1);
+    let mut widow_schedule = Schedule::default();
+    widow_schedule.add_systems(detect_widows);
+    widow_schedule.run(&mut world);
+
+    assert!(world.get::<Partner>(female1).is_none(), "Widow should lose Partner component");
+    let delay = world.get::<ReentryDelay>(female1).expect("Widow should have a ReentryDelay").0;
+    assert!(relative_eq!(delay, params.widowhood_reentry_delay, epsilon = 0.001));
+
+    // Widow does not seek a partner while waiting
+    let mut seeking_schedule = Schedule::default();
+    seeking_schedule.add_systems((update_reentry_delay, start_partner_seeking));
+    seeking_schedule.run(&mut world);
+    assert!(world.get::<PartnerSeeking>(female1).is_none(), "Widow should not seek a partner during the delay");
+
+    // Run until the delay expires (SEEKING_TIMESTEP = 1/4 year)
+    for _ in 0..5 {
+        seeking_schedule.run(&mut world);
+    }
+    assert!(world.get::<ReentryDelay>(female1).is_none(), "ReentryDelay should be removed after it expires");
+    assert!(world.get::<PartnerSeeking>(female1).is_some(), "Widow should re-enter the partner market");
+}
@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult, BirthEvent, update_age, remove_dead, initial_population};
+use bevy_abm::gestation::Mother;
+use bevy_abm::household::{Household, HouseholdMember};
+use bevy_abm::location::Location;
+use bevy_abm::patch::{Patches, InPatch, MigrationEvent, refresh_patch_parameters, migrate, assign_patch};
+use bevy_abm::config::{Args, SimulationParameters};
+
+use clap::Parser;
+
+mod common;
+
+// Two villages on a 20x20 grid, everyone in the west migrates east within a timestep
+const TWO_VILLAGES: &str = r#"{
+    "patches": [
+        {"name": "west", "center": [5.0, 10.0], "radius": 1.0},
+        {"name": "east", "center": [15.0, 10.0], "radius": 1.0, "overrides": {"death_age": 10.0}}
+    ],
+    "migration": {"rates": [[0.0, 1000.0], [0.0, 0.0]]}
+}"#;
+
+fn grid_params() -> SimulationParameters {
+    SimulationParameters {
+        grid_width: 20.0,
+        grid_height: 20.0,
+        ..Default::default()
+    }
+}
+
+fn spawn_resident(world: &mut World, age: f32, patch: usize, household: Entity) -> Entity {
+    let x = if patch == 0 { 5.0 } else { 15.0 };
+    world.spawn((
+        Individual,
+        Demog { age, sex: Sex::Female },
+        Location(Vec2::new(x, 10.0)),
+        InPatch(patch),
+        HouseholdMember(household)
+    )).id()
+}
+
+#[test]
+fn test_patch_parameter_overrides() {
+
+    let patches = Patches::from_json(TWO_VILLAGES).unwrap();
+    assert_eq!(patches.nearest(&Location(Vec2::new(12.0, 0.0))), Some(1));
+    assert!(Patches::from_json(r#"{"patches": [{"name": "a", "center": [0, 0], "radius": 1, "overrides": {"no_such_rate": 1}}]}"#).is_err(),
+        "Unknown override names should be rejected");
+    assert!(Patches::from_json(r#"{"patches": [{"name": "a", "center": [0, 0], "radius": 1, "overrides": {"grid_width": 50}}]}"#).is_err(),
+        "Overrides of parameters that apply to the whole population should be rejected");
+
+    let mut world = common::setup_world(grid_params());
+    world.insert_resource(patches);
+    let household = world.spawn(Household).id();
+    let west = spawn_resident(&mut world, 20.0, 0, household);
+    let east = spawn_resident(&mut world, 20.0, 1, household);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((refresh_patch_parameters, update_age, remove_dead).chain());
+    schedule.run(&mut world);
+
+    assert!(world.get_entity(west).is_some(), "Global death_age applies in the west");
+    assert!(world.get_entity(east).is_none(), "East overrides death_age");
+}
+
+#[test]
+fn test_individual_migration() {
+
+    let mut world = common::setup_world(grid_params());
+    world.insert_resource(Patches::from_json(TWO_VILLAGES).unwrap());
+    let household = world.spawn(Household).id();
+    let parent = spawn_resident(&mut world, 30.0, 0, household);
+    world.entity_mut(parent).insert(Adult);
+    let child = spawn_resident(&mut world, 5.0, 0, household);
+    world.entity_mut(child).insert(Mother(parent));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((migrate, assign_patch).chain());
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<InPatch>(parent), Some(&InPatch(1)), "Adult should have migrated east");
+    assert_eq!(world.get::<InPatch>(child), Some(&InPatch(1)), "Dependent child should move with the parent");
+    let new_household = world.get::<HouseholdMember>(parent).unwrap().0;
+    assert_ne!(new_household, household, "Migrant should start a new household");
+    assert_eq!(world.get::<HouseholdMember>(child).unwrap().0, new_household);
+
+    let events: Vec<MigrationEvent> = world.resource_mut::<Events<MigrationEvent>>().drain().collect();
+    assert_eq!(events.len(), 2, "Should have emitted a migration event per mover");
+    assert!(events.iter().all(|e| e.from_patch == 0 && e.to_patch == 1));
+    assert!(events.iter().all(|e| e.household == Some(new_household)));
+}
+
+#[test]
+fn test_household_migration() {
+
+    let mut patches = Patches::from_json(TWO_VILLAGES).unwrap();
+    patches.household_migration = true;
+    let mut world = common::setup_world(grid_params());
+    world.insert_resource(patches);
+    let household = world.spawn((Household, Location(Vec2::new(5.0, 10.0)))).id();
+    let members = [
+        spawn_resident(&mut world, 30.0, 0, household),
+        spawn_resident(&mut world, 70.0, 0, household),
+    ];
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((migrate, assign_patch).chain());
+    schedule.run(&mut world);
+
+    let home = *world.get::<Location>(household).unwrap();
+    assert!(home.0.x > 10.0, "Household should have moved east");
+    for e in members {
+        assert_eq!(world.get::<InPatch>(e), Some(&InPatch(1)));
+        assert_eq!(world.get::<HouseholdMember>(e).unwrap().0, household, "Members should stay in their household");
+        assert_eq!(world.get::<Location>(e), Some(&home));
+    }
+    assert_eq!(world.resource::<Events<MigrationEvent>>().len(), 2);
+}
+
+#[test]
+fn test_placement_within_radius() {
+
+    // Overlapping patches: founders of each settle within its radius, on its side of the boundary
+    let patches = Patches::from_json(r#"{"patches": [
+        {"name": "west", "center": [8.0, 10.0], "radius": 3.0},
+        {"name": "east", "center": [12.0, 10.0], "radius": 3.0}
+    ]}"#).unwrap();
+    let params = grid_params();
+
+    for i in 0..2 {
+        for _ in 0..200 {
+            let location = patches.place(i, &params);
+            assert!(location.distance(&patches.patches[i].center()) <= 3.0 + 1e-4, "Should be within the patch radius");
+            assert_eq!(patches.nearest(&location), Some(i), "Should belong to the patch it was placed in");
+        }
+    }
+}
+
+#[test]
+fn test_founders_placed_in_patches() {
+
+    let patches = Patches::from_json(TWO_VILLAGES).unwrap();
+    let mut world = common::setup_world(grid_params());
+    world.insert_resource(patches.clone());
+    world.insert_resource(Args::parse_from(["bevy_abm", "--initial-population", "50"]));
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((initial_population, assign_patch).chain());
+    schedule.run(&mut world);
+
+    // The birth record should carry the founder's place in its patch, not a spot anywhere on the grid
+    let events: Vec<BirthEvent> = world.resource_mut::<Events<BirthEvent>>().drain().collect();
+    assert_eq!(events.len(), 50);
+    for event in events {
+        let location = world.get::<Location>(event.child_entity).unwrap();
+        assert_eq!(event.location, location.coords());
+        let patch = world.get::<InPatch>(event.child_entity).unwrap().0;
+        assert!(location.distance(&patches.patches[patch].center()) <= 1.0 + 1e-4, "Founders should be placed within a patch");
+    }
+}
@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate approx;
+
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, BirthEvent};
+use bevy_abm::partner::Partner;
+use bevy_abm::gestation::{RemainingGestation, PostpartumInfecundity,
+                          update_gestation, update_postpartum_infecundity, conception};
+use bevy_abm::events::mean_birth_interval;
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+#[test]
+fn test_postpartum_infecundity_blocks_conception() {
+
+    // Six months after any delivery plus half of a one-year breastfeeding period after live births
+    let mut world = common::setup_world(SimulationParameters {
+        conception_rate: 10000.0,
+        postpartum_infecundity: 0.5,
+        breastfeeding_duration: 1.0,
+        breastfeeding_infecundity_ratio: 0.5,
+        ..Default::default()
+    });
+
+    let father = world.spawn((Individual, Demog { age: 30.0, sex: Sex::Male })).id();
+    let mother = world.spawn((
+        Individual,
+        Demog { age: 30.0, sex: Sex::Female },
+        Partner(father),
+        RemainingGestation(0.0)
+    )).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_gestation, update_postpartum_infecundity, conception).chain());
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<Events<BirthEvent>>().len(), 1);
+    let infecundity = world.get::<PostpartumInfecundity>(mother).expect("Mother should be infecund after the birth");
+    assert!(infecundity.0 > 0.9 && infecundity.0 <= 1.0, "About a year of infecundity left, got {}", infecundity.0);
+    assert!(world.get::<RemainingGestation>(mother).is_none(), "No conception while infecund");
+
+    // Weekly steps until the period ends, then conception resumes
+    for _ in 0..60 {
+        schedule.run(&mut world);
+        if world.get::<RemainingGestation>(mother).is_some() {
+            break;
+        }
+    }
+    assert!(world.get::<PostpartumInfecundity>(mother).is_none());
+    assert!(world.get::<RemainingGestation>(mother).is_some(), "Mother should conceive again after the infecund period");
+}
+
+#[test]
+fn test_mean_birth_interval() {
+
+    let birth = |mother: Option<Entity>, time: f32| BirthEvent {
+        child_entity: Entity::PLACEHOLDER,
+        mother_entity: mother,
+        father_entity: None,
+        pregnancy_id: None,
+        sex: Sex::Female,
+        maternal_transfer: Default::default(),
+        location: [0.0, 0.0],
+        time,
+    };
+    let m1 = Entity::from_raw(1);
+    let m2 = Entity::from_raw(2);
+    assert_eq!(mean_birth_interval(&[birth(None, 0.0), birth(Some(m1), 1.0)]), None);
+    let births = [birth(Some(m1), 1.0), birth(Some(m2), 2.0), birth(Some(m1), 3.0), birth(Some(m2), 6.0), birth(Some(m1), 6.0)];
+    assert_relative_eq!(mean_birth_interval(&births).unwrap(), 3.0);
+}
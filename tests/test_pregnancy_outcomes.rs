@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, BirthEvent};
+use bevy_abm::gestation::{RemainingGestation, Pregnancy, PregnancyLossHazard, PregnancyOutcome,
+                          ConceptionEvent, PregnancyEndEvent, update_gestation, log_conceptions, sample_gestation_duration};
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+#[test]
+fn test_gestation_length_distribution() {
+
+    let mut params = SimulationParameters::default();
+    assert_eq!(sample_gestation_duration(&params), params.gestation_duration, "Fixed length without sd");
+
+    params.gestation_duration_sd = 2.0 / 52.0;
+    let samples: Vec<f32> = (0..2000).map(|_| sample_gestation_duration(&params)).collect();
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    assert!((mean - params.gestation_duration).abs() < 0.5 / 52.0, "Mean should be near gestation_duration, got {}", mean);
+    assert!(samples.iter().all(|d| (d - params.gestation_duration).abs() <= 3.0 * params.gestation_duration_sd + 1e-6));
+    assert!(samples.iter().any(|d| *d != params.gestation_duration));
+}
+
+#[test]
+fn test_early_pregnancy_loss() {
+
+    // A very high loss hazard during the first 12 weeks only
+    let mut world = common::setup_world(SimulationParameters {
+        pregnancy_loss_hazards: vec![PregnancyLossHazard { min_gestation: 0.0, max_gestation: 12.0 / 52.0, rate: 10000.0 }],
+        ..Default::default()
+    });
+
+    let father = world.spawn((Individual, Demog { age: 30.0, sex: Sex::Male })).id();
+    let duration = 40.0 / 52.0;
+    let early = world.spawn((
+        Individual,
+        Demog { age: 25.0, sex: Sex::Female },
+        RemainingGestation(duration - 4.0 / 52.0),
+        Pregnancy { id: 0, father, duration }
+    )).id();
+    let late = world.spawn((
+        Individual,
+        Demog { age: 25.0, sex: Sex::Female },
+        RemainingGestation(duration - 30.0 / 52.0),
+        Pregnancy { id: 1, father, duration }
+    )).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((log_conceptions, update_gestation).chain());
+    schedule.run(&mut world);
+
+    let conceptions: Vec<ConceptionEvent> = world.resource_mut::<Events<ConceptionEvent>>().drain().collect();
+    assert_eq!(conceptions.len(), 2, "New pregnancies are logged as conceptions");
+    assert!(conceptions.iter().all(|c| c.father_entity == father && c.gestation_duration == duration));
+
+    assert!(world.get::<RemainingGestation>(early).is_none(), "Early pregnancy should be lost");
+    assert!(world.get::<Pregnancy>(early).is_none());
+    assert!(world.get::<RemainingGestation>(late).is_some(), "Later pregnancy is past the loss window");
+    assert_eq!(world.resource::<Events<BirthEvent>>().len(), 0);
+
+    let ends: Vec<PregnancyEndEvent> = world.resource_mut::<Events<PregnancyEndEvent>>().drain().collect();
+    assert_eq!(ends.len(), 1);
+    assert_eq!(ends[0].mother_entity, early);
+    assert_eq!(ends[0].outcome, PregnancyOutcome::Miscarriage);
+    assert!(ends[0].gestational_age < 12.0 / 52.0);
+
+    // Carried to term, the pregnancy ends in a live birth
+    world.entity_mut(late).insert(RemainingGestation(0.0));
+    schedule.run(&mut world);
+    let ends: Vec<PregnancyEndEvent> = world.resource_mut::<Events<PregnancyEndEvent>>().drain().collect();
+    assert_eq!(ends.len(), 1);
+    assert_eq!(ends[0].outcome, PregnancyOutcome::LiveBirth);
+    assert_eq!(world.resource::<Events<BirthEvent>>().len(), 1);
+    assert_eq!(world.resource::<Events<ConceptionEvent>>().len(), 0, "Conceptions are logged once");
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::scenario::{Scenario, ScenarioAction, ParameterChangeEvent, apply_scenario};
+use bevy_abm::config::SimulationParameters;
+
+// A step change at year 20 and a ramp of breakup_rate over years 10-30
+const TRANSITION: &str = r#"[
+    {"action": "set", "time": 20.0, "parameter": "conception_rate", "value": 0.3},
+    {"action": "ramp", "start": 10.0, "end": 30.0, "parameter": "breakup_rate", "from": 0.1, "to": 0.2}
+]"#;
+
+fn run_until(world: &mut World, schedule: &mut Schedule, years: f32) {
+    let elapsed = world.resource::<Time>().elapsed_seconds();
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(years - elapsed));
+    schedule.run(world);
+}
+
+#[test]
+fn test_scenario_timeline() {
+
+    assert!(Scenario::from_json(r#"[{"action": "set", "time": 1.0, "parameter": "no_such_rate", "value": 1.0}]"#).is_err(),
+        "Unknown parameter names should be rejected");
+
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters::default());
+    world.insert_resource(Scenario::from_json(TRANSITION).unwrap());
+    world.init_resource::<Events<ParameterChangeEvent>>();
+    world.init_resource::<Time>();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(apply_scenario);
+
+    run_until(&mut world, &mut schedule, 5.0);
+    assert_eq!(world.resource::<Events<ParameterChangeEvent>>().len(), 0, "Nothing changes before the first action");
+    assert_eq!(world.resource::<SimulationParameters>().conception_rate, 0.5);
+
+    run_until(&mut world, &mut schedule, 20.0);
+    let params = world.resource::<SimulationParameters>();
+    assert_eq!(params.conception_rate, 0.3, "Step change should apply at year 20");
+    assert!((params.breakup_rate - 0.15).abs() < 1e-6, "Ramp should be halfway at year 20");
+
+    run_until(&mut world, &mut schedule, 40.0);
+    run_until(&mut world, &mut schedule, 50.0);
+    assert!((world.resource::<SimulationParameters>().breakup_rate - 0.2).abs() < 1e-6, "Ramp should hold its end value");
+
+    let events: Vec<ParameterChangeEvent> = world.resource_mut::<Events<ParameterChangeEvent>>().drain().collect();
+    assert_eq!(events.iter().filter(|e| e.parameter == "conception_rate").count(), 1, "Step change should be logged once");
+    assert_eq!(events.iter().filter(|e| e.parameter == "breakup_rate").count(), 2, "Each ramp update should be logged, but not after its end");
+    assert!(events.iter().all(|e| e.time == 20.0 || e.time == 40.0));
+}
+
+#[test]
+fn test_scenario_built_in_code() {
+
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters::default());
+    world.insert_resource(Scenario::new(vec![
+        ScenarioAction::Set { time: 1.0, parameter: "divorce_rate".to_string(), value: 0.05 },
+    ]));
+    world.init_resource::<Events<ParameterChangeEvent>>();
+    world.init_resource::<Time>();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(apply_scenario);
+    run_until(&mut world, &mut schedule, 2.0);
+
+    assert_eq!(world.resource::<SimulationParameters>().divorce_rate, 0.05);
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, BirthEvent};
+use bevy_abm::gestation::{RemainingGestation, Parity, update_gestation, sex_ratio_at_birth};
+use bevy_abm::config::SimulationParameters;
+
+mod common;
+
+#[test]
+fn test_sex_ratio_draws() {
+
+    let males = (0..20000).filter(|_| Sex::draw(105.0) == Sex::Male).count();
+    let share = males as f32 / 20000.0;
+    assert!((share - 105.0 / 205.0).abs() < 0.015, "Male share should be about 0.512, got {}", share);
+    assert!((0..100).all(|_| Sex::draw(0.0) == Sex::Female));
+
+    // Scenarios change the ratio through set_by_name
+    let mut params = SimulationParameters::default();
+    params.set_by_name("sex_ratio_at_birth", 110.0).unwrap();
+    assert_eq!(sex_ratio_at_birth(0, &params), 110.0);
+
+    // Birth-order ratios override it, the last one for all higher orders
+    params.sex_ratio_by_birth_order = vec![105.0, 110.0, 130.0];
+    assert_eq!(sex_ratio_at_birth(0, &params), 105.0);
+    assert_eq!(sex_ratio_at_birth(1, &params), 110.0);
+    assert_eq!(sex_ratio_at_birth(5, &params), 130.0);
+}
+
+#[test]
+fn test_sex_ratio_by_birth_order() {
+
+    // First births are all girls, third and later births all boys
+    let mut world = common::setup_world(SimulationParameters {
+        sex_ratio_by_birth_order: vec![0.0, 100.0, 1.0e9],
+        ..Default::default()
+    });
+
+    let first_time = world.spawn((Individual, Demog { age: 25.0, sex: Sex::Female }, RemainingGestation(0.0))).id();
+    let experienced = world.spawn((Individual, Demog { age: 30.0, sex: Sex::Female }, RemainingGestation(0.0), Parity(2))).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_gestation);
+    schedule.run(&mut world);
+
+    let births: Vec<BirthEvent> = world.resource_mut::<Events<BirthEvent>>().drain().collect();
+    assert_eq!(births.len(), 2);
+    let first = births.iter().find(|b| b.mother_entity == Some(first_time)).unwrap();
+    let third = births.iter().find(|b| b.mother_entity == Some(experienced)).unwrap();
+    assert_eq!(first.sex, Sex::Female);
+    assert_eq!(third.sex, Sex::Male);
+    assert_eq!(world.get::<Demog>(third.child_entity).unwrap().sex, Sex::Male);
+}
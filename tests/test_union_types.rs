@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex, Adult};
+use bevy_abm::partner::{Partner, Partners, Married, Couple, UnionType, start_partner_seeking,
+                        queue_partner_seekers, match_partners, resolve_matches,
+                        random_marriages, random_breakups, AvailableSeekers,
+                        Relationship, BreakupEvent, PartnerEvent, MarriageEvent};
+use bevy_abm::config::SimulationParameters;
+
+#[test]
+fn test_cohabitation_marriage_divorce() {
+
+    // Setup world with resources
+    let mut world = World::default();
+    let params = SimulationParameters {
+        min_partner_seeking_age: 20.0,
+        max_partner_seeking_age: 50.0,
+        marriage_rate: 100.0, // Very high rate to ensure marriage
+        breakup_rate: 0.0, // Cohabiting unions never separate
+        divorce_rate: 0.0, // No divorce until we raise it below
+        ..Default::default()
+    };
+    world.insert_resource(params.clone());
+    world.insert_resource(AvailableSeekers::default());
+    world.init_resource::<Events<BreakupEvent>>();
+    world.init_resource::<Events<PartnerEvent>>();
+    world.init_resource::<Events<MarriageEvent>>();
+    world.init_resource::<Time>();
+
+    let male1 = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 25.0, sex: Sex::Male }
+    )).id();
+
+    let female1 = world.spawn((
+        Individual,
+        Adult,
+        Demog { age: 25.0, sex: Sex::Female }
+    )).id();
+
+    // Setup partnership pipeline
+    let mut partnership_schedule = Schedule::default();
+    partnership_schedule.add_systems((
+        start_partner_seeking,
+        queue_partner_seekers,
+        match_partners,
+        resolve_matches
+    ));
+
+    for _ in 0..10 {
+        partnership_schedule.run(&mut world);
+        if world.get::<Partner>(male1).is_some() && world.get::<Partner>(female1).is_some() {
+            break;
+        }
+    }
+
+    // New unions start as cohabitation
+    let union_type = world.query::<&Couple>().single(&world).union_type;
+    assert_eq!(union_type, UnionType::Cohabitation, "New union should be a cohabitation");
+    assert!(world.get::<Married>(female1).is_none(), "Cohabiting partners should not be married");
+    let rel_entity = world.query_filtered::<Entity, With<Relationship>>().single(&world);
+    assert_eq!(world.get::<Partners>(female1).map(|p| p.0.clone()), Some(vec![rel_entity]), "Steady unions are listed in Partners");
+
+    // Run marriage transitions until it happens
+    let mut marriage_schedule = Schedule::default();
+    marriage_schedule.add_systems(random_marriages);
+    for _ in 0..10 {
+        marriage_schedule.run(&mut world);
+        if world.get::<Married>(female1).is_some() {
+            break;
+        }
+    }
+
+    let union_type = world.query::<&Couple>().single(&world).union_type;
+    assert_eq!(union_type, UnionType::Marriage, "Union should have converted to marriage");
+    assert!(world.get::<Married>(male1).is_some(), "Male should be Married");
+    assert!(world.get::<Married>(female1).is_some(), "Female should be Married");
+    assert_eq!(world.resource::<Events<MarriageEvent>>().len(), 1, "Should have emitted 1 marriage event");
+
+    // Marriages dissolve at divorce_rate, not breakup_rate
+    world.resource_mut::<SimulationParameters>().divorce_rate = 100.0;
+    let mut breakup_schedule = Schedule::default();
+    breakup_schedule.add_systems(random_breakups);
+    for _ in 0..10 {
+        breakup_schedule.run(&mut world);
+        if world.get::<Partner>(female1).is_none() {
+            break;
+        }
+    }
+
+    assert!(world.get::<Partner>(female1).is_none(), "Divorce should remove Partner component");
+    assert!(world.get::<Married>(male1).is_none(), "Divorce should remove Married from male");
+    assert!(world.get::<Married>(female1).is_none(), "Divorce should remove Married from female");
+
+    let relationships = world.query_filtered::<Entity, With<Relationship>>().iter(&world).count();
+    assert_eq!(relationships, 0, "Relationship should be cleaned up after divorce");
+
+    let breakup_events = world.resource::<Events<BreakupEvent>>();
+    let mut reader = breakup_events.get_reader();
+    let breakup = reader.read(breakup_events).next().expect("Should have emitted a breakup event");
+    assert_eq!(breakup.union_type, UnionType::Marriage, "Breakup should be recorded as a divorce");
+}
+
+#[test]
+fn test_marriage_after_partner_death() {
+
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        marriage_rate: 10000.0,
+        ..Default::default()
+    });
+    world.init_resource::<Events<MarriageEvent>>();
+    world.init_resource::<Time>();
+
+    let male1 = world.spawn((Individual, Adult, Demog { age: 25.0, sex: Sex::Male })).id();
+    let female1 = world.spawn((Individual, Adult, Demog { age: 25.0, sex: Sex::Female })).id();
+    world.spawn((Relationship, Couple { e1: male1, e2: female1, union_type: UnionType::Cohabitation }));
+
+    // The partner died earlier in the frame, before detect_widows has ended the relationship
+    world.despawn(male1);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(random_marriages);
+    schedule.run(&mut world);
+
+    assert!(world.get::<Married>(female1).is_some(), "Surviving partner is still updated");
+}
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ecs::event::Events;
+
+use bevy_abm::individual::{Individual, Demog, Sex};
+use bevy_abm::infection::DiseaseState;
+use bevy_abm::vaccination::{RoutineVaccination, VaccinationCampaign, VaccinationEvent, VaccinationStrategy, Vaccinated,
+                            routine_vaccination, campaign_vaccination};
+use bevy_abm::census::{Census, take_census};
+use bevy_abm::config::SimulationParameters;
+
+fn spawn_person(world: &mut World, age: f32) -> Entity {
+    world.spawn((
+        Individual,
+        Demog { age, sex: Sex::Female },
+        DiseaseState::Susceptible
+    )).id()
+}
+
+#[test]
+fn test_routine_and_campaign_vaccination() {
+
+    let mut world = World::default();
+    world.insert_resource(SimulationParameters {
+        routine_vaccinations: vec![RoutineVaccination { age: 1.0, coverage: 1.0 }],
+        vaccination_campaigns: vec![VaccinationCampaign { time: 5.0, min_age: 10.0, max_age: 20.0, coverage: 1.0 }],
+        ..Default::default()
+    });
+    world.init_resource::<Events<VaccinationEvent>>();
+    world.init_resource::<Census>();
+    world.init_resource::<Time>();
+
+    let infant = spawn_person(&mut world, 1.0);
+    let teenager = spawn_person(&mut world, 15.0);
+    let adult = spawn_person(&mut world, 30.0);
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems((routine_vaccination, campaign_vaccination).chain());
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+
+    assert!(world.get::<Vaccinated>(infant).is_some(), "Infant should get the routine dose");
+    assert_eq!(world.get::<DiseaseState>(infant), Some(&DiseaseState::Recovered), "Vaccine should confer immunity");
+    assert!(world.get::<Vaccinated>(teenager).is_none(), "No campaign before year 5");
+    let events: Vec<VaccinationEvent> = world.resource_mut::<Events<VaccinationEvent>>().drain().collect();
+    assert_eq!(events.len(), 1, "Routine dose should be offered once");
+    assert_eq!(events[0].strategy, VaccinationStrategy::Routine);
+    assert!(events[0].protected);
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(5.0));
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+
+    assert!(world.get::<Vaccinated>(teenager).is_some(), "Campaign should reach ages 10-20");
+    assert!(world.get::<Vaccinated>(adult).is_none());
+    let events: Vec<VaccinationEvent> = world.resource_mut::<Events<VaccinationEvent>>().drain().collect();
+    assert_eq!(events.len(), 1, "Campaign should run once");
+    assert_eq!(events[0].strategy, VaccinationStrategy::Campaign);
+
+    let mut census_schedule = Schedule::default();
+    census_schedule.add_systems(take_census);
+    census_schedule.run(&mut world);
+    let census = world.resource::<Census>();
+    assert_eq!(census.vaccinations.iter().map(|r| r.population).sum::<usize>(), 3);
+    assert_eq!(census.vaccinations.iter().map(|r| r.vaccinated).sum::<usize>(), 2);
+}